@@ -0,0 +1,242 @@
+use crate::model::BoardInfo;
+use crate::sysfs::read_string;
+
+const DEVICETREE: &str = "/sys/firmware/devicetree/base";
+
+/// A devicetree string property. They end with a NUL byte
+fn property(name: &str) -> Option<String> {
+    let value = read_string(format!("{}/{}", DEVICETREE, name))?;
+    let value = value.trim_end_matches('\0').trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+/// Machine model from the devicetree. None on machines without devicetree like x86
+pub fn model() -> Option<String> {
+    property("model")
+}
+
+/// Board serial number from the devicetree
+pub fn serial() -> Option<String> {
+    property("serial-number")
+}
+
+/// Raspberry Pi revision code from the content of /proc/cpuinfo, like c03114
+fn raspberry_revision(cpuinfo: &str) -> Option<u32> {
+    let line = cpuinfo.lines().find(|line| line.starts_with("Revision"))?;
+    u32::from_str_radix(line.split_once(':')?.1.trim(), 16).ok()
+}
+
+/// Entries of the devicetree compatible property. They are separated by NUL bytes
+fn compatible_list(raw: &str) -> Vec<String> {
+    raw.split('\0').filter(|c| !c.is_empty()).map(|c| c.to_owned()).collect()
+}
+
+/// Decodes a new style revision code (bit 23 set): NOQuuuWuFMMMCCCCPPPPTTTTTTTTRRRR
+fn decode_raspberry(board: &mut BoardInfo, code: u32) {
+    if code & (1 << 23) == 0 {
+        // Old style codes only identify the first boards
+        board.revision = Some(format!("{:04x}", code & 0xffffff));
+        return;
+    }
+
+    let family = match (code >> 4) & 0xff {
+        0x00 => "Raspberry Pi A",
+        0x01 => "Raspberry Pi B",
+        0x02 => "Raspberry Pi A+",
+        0x03 => "Raspberry Pi B+",
+        0x04 => "Raspberry Pi 2B",
+        0x06 => "Raspberry Pi CM1",
+        0x08 => "Raspberry Pi 3B",
+        0x09 => "Raspberry Pi Zero",
+        0x0a => "Raspberry Pi CM3",
+        0x0c => "Raspberry Pi Zero W",
+        0x0d => "Raspberry Pi 3B+",
+        0x0e => "Raspberry Pi 3A+",
+        0x10 => "Raspberry Pi CM3+",
+        0x11 => "Raspberry Pi 4B",
+        0x12 => "Raspberry Pi Zero 2 W",
+        0x13 => "Raspberry Pi 400",
+        0x14 => "Raspberry Pi CM4",
+        0x15 => "Raspberry Pi CM4S",
+        0x17 => "Raspberry Pi 5",
+        0x18 => "Raspberry Pi CM5",
+        0x19 => "Raspberry Pi 500",
+        0x1a => "Raspberry Pi CM5 Lite",
+        _ => "Raspberry Pi"
+    };
+    let soc = match (code >> 12) & 0xf {
+        0 => Some("BCM2835"),
+        1 => Some("BCM2836"),
+        2 => Some("BCM2837"),
+        3 => Some("BCM2711"),
+        4 => Some("BCM2712"),
+        _ => None
+    };
+    let manufacturer = match (code >> 16) & 0xf {
+        0 => Some("Sony UK"),
+        1 => Some("Egoman"),
+        2 | 4 => Some("Embest"),
+        3 => Some("Sony Japan"),
+        5 => Some("Stadium"),
+        _ => None
+    };
+
+    board.family = Some(family.to_owned());
+    board.revision = Some(format!("1.{}", code & 0xf));
+    board.soc = soc.map(|soc| soc.to_owned());
+    board.manufacturer = manufacturer.map(|manufacturer| manufacturer.to_owned());
+    // 256MB shifted by the memory size field
+    board.memory = Some((256 * 1024 * 1024) << ((code >> 20) & 0x7));
+}
+
+/// Jetson module from a compatible string like nvidia,p3449-0000-b00+p3448-0002-b00
+fn decode_jetson(board: &mut BoardInfo) {
+    for compatible in &board.compatible {
+        if let Some(soc) = compatible.strip_prefix("nvidia,tegra") {
+            board.soc = Some(format!("tegra{}", soc));
+        }
+    }
+
+    // Carrier board and module are joined by +. The module is the last one
+    let id = match board.compatible.first().and_then(|id| id.strip_prefix("nvidia,")) {
+        Some(id) => id.rsplit('+').next().unwrap_or(id).to_owned(),
+        None => return
+    };
+    let mut parts = id.split('-');
+    let part = parts.next().unwrap_or_default();
+    let sku = parts.next().unwrap_or_default();
+    let family = match (part, sku) {
+        ("p3448", "0003") => "Jetson Nano 2GB",
+        ("p3448", _) | ("p3450", _) => "Jetson Nano",
+        ("p3541", _) => "Jetson Nano 2GB",
+        ("p3310", _) | ("p3489", _) | ("p2771", _) => "Jetson TX2",
+        ("p2180", _) | ("p2371", _) => "Jetson TX1",
+        ("p2888", _) | ("p2822", _) => "Jetson AGX Xavier",
+        ("p3668", _) | ("p3509", _) => "Jetson Xavier NX",
+        ("p3701", _) | ("p3737", _) => "Jetson AGX Orin",
+        ("p3767", "0000") | ("p3767", "0001") => "Jetson Orin NX",
+        ("p3767", _) | ("p3768", _) => "Jetson Orin Nano",
+        _ => return
+    };
+    board.family = Some(family.to_owned());
+    board.revision = parts.next().map(|revision| revision.to_owned());
+}
+
+/// Board identity from the devicetree. None on machines without devicetree
+pub fn board() -> Option<BoardInfo> {
+    let model = model()?;
+    let compatible = read_string(format!("{}/compatible", DEVICETREE))
+        .map(|compatible| compatible_list(&compatible))
+        .unwrap_or_default();
+
+    let mut board = BoardInfo {
+        vendor: compatible.first().and_then(|c| c.split_once(',')).map(|(vendor, _)| vendor.to_owned()),
+        model,
+        family: None,
+        revision: None,
+        serial: serial(),
+        soc: None,
+        manufacturer: None,
+        memory: None,
+        compatible
+    };
+
+    match board.vendor.as_deref() {
+        Some("raspberrypi") => {
+            let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+            if let Some(code) = raspberry_revision(&cpuinfo) {
+                decode_raspberry(&mut board, code);
+            }
+        },
+        Some("nvidia") => decode_jetson(&mut board),
+        _ => {}
+    }
+    Some(board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_board(compatible: &str) -> BoardInfo {
+        BoardInfo {
+            model: String::new(),
+            vendor: None,
+            family: None,
+            revision: None,
+            serial: None,
+            soc: None,
+            manufacturer: None,
+            memory: None,
+            compatible: compatible_list(compatible)
+        }
+    }
+
+    #[test]
+    fn raspberry_revision_from_cpuinfo() {
+        let cpuinfo = "processor\t: 3\nHardware\t: BCM2835\nRevision\t: c03114\nSerial\t\t: 10000000abcdef01\n";
+        assert_eq!(raspberry_revision(cpuinfo), Some(0xc03114));
+        assert_eq!(raspberry_revision("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn raspberry_4b() {
+        let mut board = empty_board("raspberrypi,4-model-b\0brcm,bcm2711\0");
+        decode_raspberry(&mut board, 0xc03114);
+        assert_eq!(board.family.as_deref(), Some("Raspberry Pi 4B"));
+        assert_eq!(board.revision.as_deref(), Some("1.4"));
+        assert_eq!(board.soc.as_deref(), Some("BCM2711"));
+        assert_eq!(board.manufacturer.as_deref(), Some("Sony UK"));
+        assert_eq!(board.memory, Some(4 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn raspberry_zero_2_w() {
+        let mut board = empty_board("raspberrypi,model-zero-2-w\0brcm,bcm2837\0");
+        decode_raspberry(&mut board, 0x902120);
+        assert_eq!(board.family.as_deref(), Some("Raspberry Pi Zero 2 W"));
+        assert_eq!(board.revision.as_deref(), Some("1.0"));
+        assert_eq!(board.soc.as_deref(), Some("BCM2837"));
+        assert_eq!(board.manufacturer.as_deref(), Some("Sony UK"));
+        assert_eq!(board.memory, Some(512 * 1024 * 1024));
+    }
+
+    #[test]
+    fn raspberry_old_style_revision() {
+        let mut board = empty_board("");
+        decode_raspberry(&mut board, 0x000e);
+        assert_eq!(board.revision.as_deref(), Some("000e"));
+        assert_eq!(board.family, None);
+        assert_eq!(board.memory, None);
+    }
+
+    #[test]
+    fn jetson_nano() {
+        let mut board = empty_board("nvidia,p3449-0000-b00+p3448-0002-b00\0nvidia,jetson-nano\0nvidia,tegra210\0");
+        decode_jetson(&mut board);
+        assert_eq!(board.family.as_deref(), Some("Jetson Nano"));
+        assert_eq!(board.revision.as_deref(), Some("b00"));
+        assert_eq!(board.soc.as_deref(), Some("tegra210"));
+    }
+
+    #[test]
+    fn jetson_orin_nx() {
+        let mut board = empty_board("nvidia,p3768-0000+p3767-0000\0nvidia,p3767-0000\0nvidia,tegra234\0");
+        decode_jetson(&mut board);
+        assert_eq!(board.family.as_deref(), Some("Jetson Orin NX"));
+        assert_eq!(board.revision, None);
+        assert_eq!(board.soc.as_deref(), Some("tegra234"));
+    }
+
+    #[test]
+    fn unknown_jetson() {
+        let mut board = empty_board("nvidia,p9999-0000\0nvidia,tegra186\0");
+        decode_jetson(&mut board);
+        assert_eq!(board.family, None);
+        assert_eq!(board.soc.as_deref(), Some("tegra186"));
+    }
+}
@@ -1,35 +1,289 @@
-//! V4l list cameras feature
-use v4l::context;
-use crate::model::Camera;
+//! List the cameras attached to the machine. With the `v4l` feature they are queried through libv4l,
+//! otherwise they are read from sysfs
+#[cfg(feature = "v4l")]
+use anyhow::Result;
+#[cfg(feature = "v4l")]
+use v4l::{Device, FourCC, Fraction};
+#[cfg(feature = "v4l")]
+use v4l::capability::Flags;
+#[cfg(feature = "v4l")]
+use v4l::control;
+#[cfg(feature = "v4l")]
+use v4l::format::description::Flags as FormatFlags;
+#[cfg(feature = "v4l")]
+use v4l::frameinterval::FrameIntervalEnum;
+#[cfg(feature = "v4l")]
+use v4l::framesize::FrameSizeEnum;
+#[cfg(feature = "v4l")]
+use v4l::video::Capture;
+#[cfg(feature = "v4l")]
 use std::panic;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use crate::model::Camera;
+#[cfg(feature = "v4l")]
+use crate::model::{CameraCapabilities, CameraFormat, CameraMode, CameraSizeRange, CameraControl};
+use crate::sysfs::{read_string, read_value, numbered_entries, driver, link_name};
+
+const VIDEO4LINUX: &str = "/sys/class/video4linux";
+
+/// Persistent links of udev (/dev/v4l/by-id or by-path) by the node they point to
+fn persistent_links(dir: &str) -> BTreeMap<PathBuf, String> {
+    let mut links = BTreeMap::new();
+    for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        if let Ok(target) = std::fs::canonicalize(entry.path()) {
+            links.insert(target, entry.path().to_string_lossy().into_owned());
+        }
+    }
+    links
+}
+
+/// The USB device a video node belongs to. The node device is an interface of it
+fn usb_device(device: &Path) -> Option<&Path> {
+    device.ancestors().find(|dir| dir.join("idVendor").exists())
+}
+
+fn read_id(path: impl AsRef<Path>) -> Option<u16> {
+    u16::from_str_radix(&read_string(path)?, 16).ok()
+}
+
+/// A UVC camera is a USB interface bound to uvcvideo
+fn is_uvc(device: &Path) -> bool {
+    link_name(device.join("subsystem")).as_deref() == Some("usb") && driver(device).as_deref() == Some("uvcvideo")
+}
+
+/// Groups the video nodes (number, name and if it is a metadata node when known) of the same UVC camera,
+/// which has a capture and a metadata node. The capture one is used as the camera path. Other devices
+/// sharing a parent (multi-input capture cards, codecs and ISPs) have independent nodes, so every node
+/// is a camera on its own
+fn logical_cameras(nodes: Vec<(u32, String, Option<bool>)>) -> Vec<Camera> {
+    let by_id = persistent_links("/dev/v4l/by-id");
+    let by_path = persistent_links("/dev/v4l/by-path");
+
+    let mut devices: BTreeMap<PathBuf, Vec<(bool, u32, u32, String)>> = BTreeMap::new();
+    for (number, name, metadata) in nodes {
+        let dir = Path::new(VIDEO4LINUX).join(format!("video{}", number));
+        // Nodes without device (like vivid or loopback) use their own directory
+        let device = std::fs::canonicalize(dir.join("device")).unwrap_or_else(|_| dir.clone());
+        let group = if is_uvc(&device) { device } else { dir.clone() };
+        // Without v4l the stream index tells the nodes apart: uvcvideo creates the capture node first
+        let index = read_value::<u32>(dir.join("index")).unwrap_or(0);
+        devices.entry(group).or_default().push((metadata.unwrap_or(false), index, number, name));
+    }
+
+    let mut cameras = vec![];
+    for (_, mut nodes) in devices {
+        nodes.sort();
+        let (_, _, number, name) = nodes[0].clone();
+        let dir = Path::new(VIDEO4LINUX).join(format!("video{}", number));
+        let device = std::fs::canonicalize(dir.join("device")).unwrap_or(dir);
+        let path = format!("/dev/video{}", number);
+        let node = std::fs::canonicalize(&path).unwrap_or_else(|_| PathBuf::from(&path));
+        let usb = usb_device(&device);
+        cameras.push((number, Camera {
+            name,
+            by_id: by_id.get(&node).cloned(),
+            by_path: by_path.get(&node).cloned(),
+            nodes: nodes.iter().map(|(_, _, number, _)| format!("/dev/video{}", number)).collect(),
+            vendor_id: usb.and_then(|usb| read_id(usb.join("idVendor"))),
+            product_id: usb.and_then(|usb| read_id(usb.join("idProduct"))),
+            serial: usb.and_then(|usb| read_string(usb.join("serial"))),
+            bus_path: usb.and_then(|usb| usb.file_name()).map(|name| name.to_string_lossy().into_owned()),
+            driver: driver(&device),
+            path
+        }));
+    }
+    cameras.sort_by_key(|(number, _)| *number);
+    cameras.into_iter().map(|(_, camera)| camera).collect()
+}
+
 /// List of attached cameras to the machine
 /// Example
 /// ```
-/// use machine_info::Machine;
-/// 
-/// println!("{:?}", Machine::list_cameras());
-/// 
+/// use machine_info::camera;
+///
+/// println!("{:?}", camera::list_cameras());
+///
 /// ```
+#[cfg(feature = "v4l")]
 pub fn list_cameras() -> Vec<Camera> {
-    let mut cameras = vec![];
-
-    // I catch panic because the library uses unwrap internally and sometimes the device has no name
-    for dev in context::enum_devices() {
-        let name = panic::catch_unwind(|| {
-            dev.name().unwrap()
-        });
-    
-        let name = match name {
-            Ok(name) => name,
-            Err(_) => "Unknown".to_owned()
-            
-        };
-        
-        cameras.push(Camera {
-            name,
-            path: dev.path().as_os_str().to_str().unwrap_or("Unknown").to_owned()
+    // The name given by the driver, or the sysfs one if the node cannot be opened
+    let nodes = numbered_entries(VIDEO4LINUX, "video")
+        .into_iter()
+        .map(|(number, dir)| {
+            let caps = Device::with_path(format!("/dev/video{}", number))
+                .and_then(|device| device.query_caps())
+                .ok();
+            let metadata = caps.as_ref().map(|caps| {
+                caps.capabilities.contains(Flags::META_CAPTURE) && !caps.capabilities.contains(Flags::VIDEO_CAPTURE)
+            });
+            let name = caps.map(|caps| caps.card)
+                .or_else(|| read_string(dir.join("name")))
+                .unwrap_or_else(|| "Unknown".to_owned());
+            (number, name, metadata)
         })
+        .collect();
+    logical_cameras(nodes)
+}
+
+/// List of attached cameras to the machine
+/// Example
+/// ```
+/// use machine_info::camera;
+///
+/// println!("{:?}", camera::list_cameras());
+///
+/// ```
+#[cfg(not(feature = "v4l"))]
+pub fn list_cameras() -> Vec<Camera> {
+    sysfs_cameras()
+}
+
+/// List of attached cameras read from /sys/class/video4linux. It does not need libv4l so it is
+/// available without the `v4l` feature
+/// Example
+/// ```
+/// use machine_info::camera;
+///
+/// for camera in camera::sysfs_cameras() {
+///   println!("{} {}", camera.name, camera.path);
+/// }
+/// ```
+pub fn sysfs_cameras() -> Vec<Camera> {
+    let nodes = numbered_entries(VIDEO4LINUX, "video")
+        .into_iter()
+        .map(|(number, dir)| (number, read_string(dir.join("name")).unwrap_or_else(|| "Unknown".to_owned()), None))
+        .collect();
+    logical_cameras(nodes)
+}
+
+/// Frames per second of a frame interval (seconds per frame)
+#[cfg(feature = "v4l")]
+fn frame_rate(interval: &Fraction) -> Option<f64> {
+    if interval.numerator == 0 {
+        None
+    } else {
+        Some(interval.denominator as f64 / interval.numerator as f64)
     }
-    cameras
+}
 
-}
\ No newline at end of file
+#[cfg(feature = "v4l")]
+fn mode(device: &Device, fourcc: FourCC, width: u32, height: u32) -> CameraMode {
+    let mut frame_rates = vec![];
+    for interval in device.enum_frameintervals(fourcc, width, height).unwrap_or_default() {
+        match interval.interval {
+            FrameIntervalEnum::Discrete(interval) => frame_rates.extend(frame_rate(&interval)),
+            FrameIntervalEnum::Stepwise(stepwise) => {
+                frame_rates.extend(frame_rate(&stepwise.min));
+                frame_rates.extend(frame_rate(&stepwise.max));
+            }
+        }
+    }
+    frame_rates.sort_by(|a, b| b.total_cmp(a));
+    frame_rates.dedup();
+    CameraMode { width, height, frame_rates }
+}
+
+#[cfg(feature = "v4l")]
+fn format(device: &Device, description: v4l::format::Description) -> CameraFormat {
+    let fourcc = description.fourcc;
+    let mut modes = vec![];
+    let mut size_range = None;
+    for size in device.enum_framesizes(fourcc).unwrap_or_default() {
+        match size.size {
+            FrameSizeEnum::Discrete(size) => modes.push(mode(device, fourcc, size.width, size.height)),
+            // Listing every size of a continuous range would give millions of modes
+            FrameSizeEnum::Stepwise(stepwise) => {
+                modes.push(mode(device, fourcc, stepwise.min_width, stepwise.min_height));
+                modes.push(mode(device, fourcc, stepwise.max_width, stepwise.max_height));
+                size_range = Some(CameraSizeRange {
+                    min_width: stepwise.min_width,
+                    max_width: stepwise.max_width,
+                    step_width: stepwise.step_width,
+                    min_height: stepwise.min_height,
+                    max_height: stepwise.max_height,
+                    step_height: stepwise.step_height
+                });
+            }
+        }
+    }
+    CameraFormat {
+        fourcc: fourcc.to_string().trim().to_owned(),
+        description: description.description,
+        compressed: description.flags.contains(FormatFlags::COMPRESSED),
+        emulated: description.flags.contains(FormatFlags::EMULATED),
+        modes,
+        size_range
+    }
+}
+
+#[cfg(feature = "v4l")]
+fn controls(device: &Device) -> Vec<CameraControl> {
+    // I catch panic because the library unwraps the control type and panics with the compound types it
+    // does not know, like the codec ones. It fails when the node has no controls at all
+    let controls = panic::catch_unwind(panic::AssertUnwindSafe(|| device.query_controls()));
+    controls
+        .ok()
+        .and_then(|controls| controls.ok())
+        .unwrap_or_default()
+        .into_iter()
+        // Control classes are only headers grouping the next controls
+        .filter(|description| description.typ != control::Type::CtrlClass && !description.flags.contains(control::Flags::DISABLED))
+        .map(|description| CameraControl {
+            id: description.id,
+            name: description.name,
+            kind: description.typ.to_string(),
+            minimum: description.minimum,
+            maximum: description.maximum,
+            step: description.step,
+            default: description.default,
+            read_only: description.flags.contains(control::Flags::READ_ONLY),
+            inactive: description.flags.contains(control::Flags::INACTIVE),
+            menu: description.items
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, item)| item.to_string())
+                .collect()
+        })
+        .collect()
+}
+
+/// Capabilities of a video node: if it captures frames or metadata, its pixel formats with the frame
+/// sizes and rates of each one and its controls (only for capture nodes). Use it to pick a capture mode.
+/// The node is only queried, so it works even if another process is streaming from it
+/// Example
+/// ```no_run
+/// use machine_info::camera;
+///
+/// let capabilities = camera::capabilities("/dev/video0").unwrap();
+/// for format in capabilities.formats {
+///   for mode in format.modes {
+///     println!("{} {}x{} {:?}", format.fourcc, mode.width, mode.height, mode.frame_rates);
+///   }
+/// }
+/// ```
+#[cfg(feature = "v4l")]
+pub fn capabilities(path: &str) -> Result<CameraCapabilities> {
+    let device = Device::with_path(path)?;
+    let caps = device.query_caps()?;
+    // Memory to memory nodes are codecs, scalers or ISPs, not cameras, and their controls are not queried
+    let camera = caps.capabilities.intersects(Flags::VIDEO_CAPTURE | Flags::VIDEO_CAPTURE_MPLANE)
+        && !caps.capabilities.intersects(Flags::VIDEO_M2M | Flags::VIDEO_M2M_MPLANE);
+    Ok(CameraCapabilities {
+        path: path.to_owned(),
+        // The formats are enumerated with the single-planar buffer type only
+        capture: caps.capabilities.contains(Flags::VIDEO_CAPTURE),
+        multiplanar: caps.capabilities.contains(Flags::VIDEO_CAPTURE_MPLANE),
+        metadata: caps.capabilities.contains(Flags::META_CAPTURE),
+        streaming: caps.capabilities.contains(Flags::STREAMING),
+        driver: caps.driver,
+        card: caps.card,
+        bus: caps.bus,
+        formats: device.enum_formats()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|description| format(&device, description))
+            .collect(),
+        controls: if camera { controls(&device) } else { vec![] }
+    })
+}
@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+use crate::model::{CoreFrequency, CpuTopology, LogicalCpu, CpuCache};
+use crate::sysfs::{read_string, read_value, read_cpu_list, numbered_entries};
+
+const CPU: &str = "/sys/devices/system/cpu";
+
+// Frequencies are exposed in kHz
+fn read_mhz(path: impl AsRef<std::path::Path>) -> Option<u64> {
+    read_value::<u64>(path).map(|value| value / 1000)
+}
+
+/// Live frequency, limits, governor and throttle counters of every CPU
+pub fn frequencies() -> Vec<CoreFrequency> {
+    let mut cores = vec![];
+    for (cpu, dir) in numbered_entries(CPU, "cpu") {
+        let cpufreq = dir.join("cpufreq");
+        let throttle = dir.join("thermal_throttle");
+        cores.push(CoreFrequency {
+            cpu,
+            current: read_mhz(cpufreq.join("scaling_cur_freq")),
+            min: read_mhz(cpufreq.join("scaling_min_freq")),
+            max: read_mhz(cpufreq.join("scaling_max_freq")),
+            hardware_min: read_mhz(cpufreq.join("cpuinfo_min_freq")),
+            hardware_max: read_mhz(cpufreq.join("cpuinfo_max_freq")),
+            governor: read_string(cpufreq.join("scaling_governor")),
+            energy_performance_preference: read_string(cpufreq.join("energy_performance_preference")),
+            core_throttle_count: read_value(throttle.join("core_throttle_count")),
+            package_throttle_count: read_value(throttle.join("package_throttle_count"))
+        });
+    }
+    cores
+}
+
+// Cache sizes are like 32K or 8M
+fn parse_size(size: &str) -> Option<u64> {
+    let (value, unit) = match size.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((index, _)) => size.split_at(index),
+        None => (size, "")
+    };
+    let multiplier = match unit {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => return None
+    };
+    Some(value.parse::<u64>().ok()? * multiplier)
+}
+
+/// Flags of the first processor in /proc/cpuinfo. x86 names them flags and ARM features
+pub fn cpu_flags() -> Vec<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+    for line in cpuinfo.lines() {
+        if let Some((field, value)) = line.split_once(':') {
+            if matches!(field.trim(), "flags" | "Features") {
+                return value.split_whitespace().map(|flag| flag.to_owned()).collect();
+            }
+        }
+    }
+    vec![]
+}
+
+/// Sockets, cores, SMT siblings, NUMA membership, caches and flags of the CPUs
+pub fn topology() -> CpuTopology {
+    let mut cpus = vec![];
+    let mut caches = vec![];
+    let mut seen_caches = BTreeSet::new();
+    for cpu in read_cpu_list(format!("{}/online", CPU)) {
+        let dir = std::path::Path::new(CPU).join(format!("cpu{}", cpu));
+        let topology = dir.join("topology");
+
+        for (_, index) in numbered_entries(dir.join("cache"), "index") {
+            let level = read_value::<u32>(index.join("level"));
+            let kind = read_string(index.join("type"));
+            let shared_cpus = read_cpu_list(index.join("shared_cpu_list"));
+            if let (Some(level), Some(kind)) = (level, kind) {
+                // Caches shared by several CPUs are reported once
+                if seen_caches.insert((level, kind.clone(), shared_cpus.clone())) {
+                    caches.push(CpuCache {
+                        level,
+                        kind,
+                        size: read_string(index.join("size")).and_then(|size| parse_size(&size)),
+                        shared_cpus
+                    });
+                }
+            }
+        }
+
+        cpus.push(LogicalCpu {
+            cpu,
+            socket: read_value(topology.join("physical_package_id")).unwrap_or(0),
+            core: read_value(topology.join("core_id")).unwrap_or(0),
+            node: numbered_entries(&dir, "node").first().map(|(node, _)| *node),
+            siblings: read_cpu_list(topology.join("thread_siblings_list"))
+        });
+    }
+
+    let sockets = cpus.iter().map(|cpu| cpu.socket).collect::<BTreeSet<_>>().len();
+    let physical_cores = cpus.iter().map(|cpu| (cpu.socket, cpu.core)).collect::<BTreeSet<_>>().len();
+    CpuTopology {
+        sockets,
+        physical_cores,
+        threads_per_core: cpus.iter().map(|cpu| cpu.siblings.len()).max().unwrap_or(1),
+        online: cpus.iter().map(|cpu| cpu.cpu).collect(),
+        offline: read_cpu_list(format!("{}/offline", CPU)),
+        cpus,
+        caches,
+        flags: cpu_flags()
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+use crate::model::DmiInfo;
+use crate::sysfs::read_string;
+
+const DMI: &str = "/sys/class/dmi/id";
+
+/// Values vendors leave when they do not fill the field
+const PLACEHOLDERS: [&str; 8] = [
+    "To Be Filled By O.E.M.",
+    "To be filled by O.E.M.",
+    "Default string",
+    "Not Specified",
+    "Not Applicable",
+    "System Product Name",
+    "System Serial Number",
+    "0123456789",
+];
+
+/// A DMI field. Serial numbers and UUID are readable only by root so they are None for other users
+fn field(name: &str) -> Option<String> {
+    read_string(Path::new(DMI).join(name)).filter(|value| !PLACEHOLDERS.contains(&value.as_str()))
+}
+
+/// SMBIOS chassis type names
+fn chassis_type(code: u32) -> Option<&'static str> {
+    let name = match code {
+        1 => "Other",
+        2 => "Unknown",
+        3 => "Desktop",
+        4 => "Low Profile Desktop",
+        5 => "Pizza Box",
+        6 => "Mini Tower",
+        7 => "Tower",
+        8 => "Portable",
+        9 => "Laptop",
+        10 => "Notebook",
+        11 => "Hand Held",
+        12 => "Docking Station",
+        13 => "All in One",
+        14 => "Sub Notebook",
+        15 => "Space-saving",
+        16 => "Lunch Box",
+        17 => "Main Server Chassis",
+        18 => "Expansion Chassis",
+        19 => "SubChassis",
+        20 => "Bus Expansion Chassis",
+        21 => "Peripheral Chassis",
+        22 => "RAID Chassis",
+        23 => "Rack Mount Chassis",
+        24 => "Sealed-case PC",
+        25 => "Multi-system Chassis",
+        26 => "Compact PCI",
+        27 => "Advanced TCA",
+        28 => "Blade",
+        29 => "Blade Enclosure",
+        30 => "Tablet",
+        31 => "Convertible",
+        32 => "Detachable",
+        33 => "IoT Gateway",
+        34 => "Embedded PC",
+        35 => "Mini PC",
+        36 => "Stick PC",
+        _ => return None
+    };
+    Some(name)
+}
+
+/// Hardware identity from the DMI tables. None on machines without DMI like most ARM boards
+pub fn dmi() -> Option<DmiInfo> {
+    if !Path::new(DMI).exists() {
+        return None;
+    }
+    Some(DmiInfo {
+        sys_vendor: field("sys_vendor"),
+        product_name: field("product_name"),
+        product_version: field("product_version"),
+        product_serial: field("product_serial"),
+        product_uuid: field("product_uuid"),
+        board_vendor: field("board_vendor"),
+        board_name: field("board_name"),
+        board_version: field("board_version"),
+        bios_vendor: field("bios_vendor"),
+        bios_version: field("bios_version"),
+        bios_date: field("bios_date"),
+        chassis_type: field("chassis_type")
+            .and_then(|code| code.parse::<u32>().ok())
+            .and_then(chassis_type)
+            .map(|name| name.to_owned())
+    })
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use std::path::Path;
+use sha2::{Digest, Sha256};
+use crate::model::{Fingerprint, FingerprintSource};
+use crate::sysfs::{read_string, read_value, link_name};
+use crate::{board, dmi};
+
+/// Version of the fingerprint algorithm. It changes if the sources or the way they are combined change
+const VERSION: u32 = 1;
+
+fn machine_id() -> Option<String> {
+    // Old systems without systemd only have the D-Bus one
+    read_string("/etc/machine-id").or_else(|| read_string("/var/lib/dbus/machine-id"))
+}
+
+/// Request of ETHTOOL_GPERMADDR. 32 bytes is the longest hardware address (MAX_ADDR_LEN)
+#[repr(C)]
+struct PermanentAddress {
+    cmd: u32,
+    size: u32,
+    data: [u8; 32],
+}
+
+const ETHTOOL_GPERMADDR: u32 = 0x20;
+
+/// Address burnt into the card, read like `ethtool -P`. Unlike the current address it does not change
+/// when something sets it (NetworkManager randomization, bonding, `ip link set address`). None if the
+/// driver does not report it
+fn permanent_address(interface: &str) -> Option<String> {
+    if interface.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+    let mut request = PermanentAddress { cmd: ETHTOOL_GPERMADDR, size: 32, data: [0; 32] };
+    // SAFETY: ifreq is a plain C struct, the name is NUL terminated because it is shorter than IFNAMSIZ
+    // and the request outlives the ioctl
+    unsafe {
+        let mut ifreq: libc::ifreq = std::mem::zeroed();
+        for (name, &byte) in ifreq.ifr_name.iter_mut().zip(interface.as_bytes()) {
+            *name = byte as libc::c_char;
+        }
+        ifreq.ifr_ifru.ifru_data = &mut request as *mut PermanentAddress as *mut libc::c_char;
+        let socket = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if socket < 0 {
+            return None;
+        }
+        let result = libc::ioctl(socket, libc::SIOCETHTOOL as _, &mut ifreq);
+        libc::close(socket);
+        if result < 0 {
+            return None;
+        }
+    }
+    let address = &request.data[..(request.size as usize).min(request.data.len())];
+    if address.iter().all(|&byte| byte == 0) {
+        return None;
+    }
+    Some(address.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(":"))
+}
+
+/// The current address when the permanent one is not reported. Only if it was never set, and never for
+/// interfaces whose address is commonly set at runtime: Wi-Fi (scan randomization) and bond slaves
+fn current_address(dir: &Path) -> Option<String> {
+    if read_value::<u32>(dir.join("addr_assign_type")) != Some(0) {
+        return None;
+    }
+    if dir.join("phy80211").exists() || dir.join("wireless").exists() || dir.join("bonding_slave").exists() {
+        return None;
+    }
+    read_string(dir.join("address"))
+        .map(|address| address.to_lowercase())
+        .filter(|address| address != "00:00:00:00:00:00")
+}
+
+/// Permanent MAC addresses of the primary network interfaces, sorted. Virtual interfaces (bridges,
+/// veth, docker...) have no device. USB adapters (Ethernet and Wi-Fi dongles) are left out because
+/// they come and go
+fn mac_addresses() -> Option<String> {
+    let mut addresses = vec![];
+    for entry in std::fs::read_dir("/sys/class/net").ok()?.flatten() {
+        let dir = entry.path();
+        if !dir.join("device").exists() {
+            continue;
+        }
+        if link_name(dir.join("device").join("subsystem")).as_deref() == Some("usb") {
+            continue;
+        }
+        let address = permanent_address(&entry.file_name().to_string_lossy()).or_else(|| current_address(&dir));
+        if let Some(address) = address {
+            addresses.push(address);
+        }
+    }
+    addresses.sort();
+    addresses.dedup();
+    if addresses.is_empty() {
+        None
+    } else {
+        Some(addresses.join(","))
+    }
+}
+
+/// Hashes the available identity sources. See `Machine::fingerprint` for the stability policy
+pub fn fingerprint() -> Result<Fingerprint> {
+    let dmi = dmi::dmi();
+    // The order is part of the algorithm, do not change it without changing the version
+    let sources = [
+        ("machine-id", machine_id()),
+        ("dmi-uuid", dmi.as_ref().and_then(|dmi| dmi.product_uuid.clone())),
+        ("dmi-serial", dmi.as_ref().and_then(|dmi| dmi.product_serial.clone())),
+        ("devicetree-serial", board::serial()),
+        ("mac", mac_addresses()),
+    ];
+
+    if sources.iter().all(|(_, value)| value.is_none()) {
+        return Err(anyhow::anyhow!("No identity source available to fingerprint the machine"));
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("v{}\n", VERSION));
+    for (name, value) in &sources {
+        if let Some(value) = value {
+            hasher.update(format!("{}={}\n", name, value.trim().to_lowercase()));
+        }
+    }
+    let id = hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+
+    Ok(Fingerprint {
+        id,
+        version: VERSION,
+        sources: sources.iter().map(|(name, value)| FingerprintSource {
+            name: name.to_string(),
+            used: value.is_some()
+        }).collect()
+    })
+}
+
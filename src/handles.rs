@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::model::DeviceUser;
+
+/// Device nodes a path refers to
+enum Selector {
+    /// A node like /dev/video0. /dev/video10 does not match it
+    Node(PathBuf),
+    /// Every node inside a directory like /dev/dri
+    Directory(PathBuf),
+    /// Every node whose path starts with a text like /dev/nvidia (nvidia0, nvidiactl, nvidia-uvm...)
+    Prefix(String),
+}
+
+impl Selector {
+    fn new(path: &str) -> Selector {
+        // Persistent links like /dev/v4l/by-id/... point to the real node
+        match fs::canonicalize(path) {
+            Ok(path) if path.is_dir() => Selector::Directory(path),
+            Ok(path) => Selector::Node(path),
+            // A numbered node that is gone (like an unplugged camera) can still be open, so it is not a prefix
+            Err(_) if path.ends_with(|c: char| c.is_ascii_digit()) => Selector::Node(PathBuf::from(path)),
+            Err(_) => Selector::Prefix(path.to_owned())
+        }
+    }
+
+    fn matches(&self, target: &Path) -> bool {
+        match self {
+            // The target of a removed node ends with (deleted)
+            Selector::Node(path) => {
+                let target = target.to_string_lossy();
+                target.strip_suffix(" (deleted)").unwrap_or(&target) == path.to_string_lossy()
+            },
+            Selector::Directory(path) => target.starts_with(path),
+            Selector::Prefix(prefix) => target.to_string_lossy().starts_with(prefix.as_str())
+        }
+    }
+}
+
+/// Device nodes selected by `selector` opened by a process. Its file descriptors are links to the opened files
+fn opened_devices(pid: i32, selector: &Selector) -> Vec<String> {
+    let mut devices = vec![];
+    for entry in fs::read_dir(format!("/proc/{}/fd", pid)).into_iter().flatten().flatten() {
+        if let Ok(target) = fs::read_link(entry.path()) {
+            if selector.matches(&target) {
+                devices.push(target.to_string_lossy().into_owned());
+            }
+        }
+    }
+    devices.sort();
+    devices.dedup();
+    devices
+}
+
+/// Processes holding open a device node, any node inside a directory like /dev/dri or, if the path does
+/// not exist, any node starting with it like /dev/nvidia
+pub fn device_users(path: &str) -> Vec<DeviceUser> {
+    let selector = Selector::new(path);
+
+    let mut users = vec![];
+    for entry in fs::read_dir("/proc").into_iter().flatten().flatten() {
+        let pid = match entry.file_name().to_str().and_then(|name| name.parse::<i32>().ok()) {
+            Some(pid) => pid,
+            None => continue
+        };
+        // The file descriptors of other users' processes are readable only by root
+        let devices = opened_devices(pid, &selector);
+        if !devices.is_empty() {
+            users.push(DeviceUser {
+                pid,
+                name: fs::read_to_string(format!("/proc/{}/comm", pid)).unwrap_or_default().trim().to_string(),
+                devices
+            });
+        }
+    }
+    users.sort_by_key(|user| user.pid);
+    users
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_node_is_exact() {
+        let selector = Selector::new("/dev/video-missing7");
+        assert!(selector.matches(Path::new("/dev/video-missing7")));
+        assert!(selector.matches(Path::new("/dev/video-missing7 (deleted)")));
+        assert!(!selector.matches(Path::new("/dev/video-missing71")));
+        assert!(!selector.matches(Path::new("/dev/video-missing7 (deleted) ")));
+    }
+
+    #[test]
+    fn existing_node_is_exact() {
+        let selector = Selector::new("/dev/null");
+        assert!(selector.matches(Path::new("/dev/null")));
+        assert!(selector.matches(Path::new("/dev/null (deleted)")));
+        assert!(!selector.matches(Path::new("/dev/null2")));
+    }
+
+    #[test]
+    fn missing_name_is_prefix() {
+        let selector = Selector::new("/dev/nvidia-missing");
+        assert!(selector.matches(Path::new("/dev/nvidia-missing0")));
+        assert!(selector.matches(Path::new("/dev/nvidia-missingctl")));
+        assert!(!selector.matches(Path::new("/dev/null")));
+    }
+
+    #[test]
+    fn directory() {
+        let selector = Selector::new("/dev");
+        assert!(selector.matches(Path::new("/dev/null")));
+        assert!(!selector.matches(Path::new("/devices/null")));
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use crate::model::{SensorChip, SensorReading};
+use crate::sysfs::{read_string, read_value, numbered_entries};
+
+const HWMON: &str = "/sys/class/hwmon";
+
+/// Channel numbers of a kind of sensor, like 1 and 2 for temp1_input and temp2_input
+fn channels(dir: &Path, prefix: &str, input: &str) -> BTreeSet<u32> {
+    let mut channels = BTreeSet::new();
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let channel = name.to_str()
+                .and_then(|name| name.strip_prefix(prefix))
+                .and_then(|name| name.strip_suffix(input))
+                .and_then(|channel| channel.parse::<u32>().ok());
+            if let Some(channel) = channel {
+                channels.insert(channel);
+            }
+        }
+    }
+    channels
+}
+
+/// Reads every channel of a kind. The raw values are divided by `scale` to get the standard unit
+fn readings(dir: &Path, prefix: &str, input: &str, scale: f64) -> Vec<SensorReading> {
+    let value = |channel: u32, attribute: &str| {
+        read_value::<f64>(dir.join(format!("{}{}_{}", prefix, channel, attribute))).map(|value| value / scale)
+    };
+
+    let mut readings = vec![];
+    for channel in channels(dir, prefix, input) {
+        // The input fails to read when the sensor is not connected
+        let current = match value(channel, input.trim_start_matches('_')) {
+            Some(current) => current,
+            None => continue
+        };
+        readings.push(SensorReading {
+            label: read_string(dir.join(format!("{}{}_label", prefix, channel)))
+                .unwrap_or_else(|| format!("{}{}", prefix, channel)),
+            value: current,
+            min: value(channel, "min"),
+            max: value(channel, "max"),
+            crit: value(channel, "crit")
+        });
+    }
+    readings
+}
+
+fn chip(dir: PathBuf) -> Option<SensorChip> {
+    // Old drivers put the attributes in the device directory
+    let dir = if dir.join("name").exists() { dir } else { dir.join("device") };
+    let name = read_string(dir.join("name"))?;
+    let mut power = readings(&dir, "power", "_input", 1_000_000.0);
+    if power.is_empty() {
+        power = readings(&dir, "power", "_average", 1_000_000.0);
+    }
+    Some(SensorChip {
+        name,
+        temperatures: readings(&dir, "temp", "_input", 1000.0),
+        fans: readings(&dir, "fan", "_input", 1.0),
+        voltages: readings(&dir, "in", "_input", 1000.0),
+        currents: readings(&dir, "curr", "_input", 1000.0),
+        power
+    })
+}
+
+/// Hardware monitoring chips (coretemp, k10temp, nvme, nct6775...) with their sensors
+pub fn sensors() -> Vec<SensorChip> {
+    numbered_entries(HWMON, "hwmon")
+        .into_iter()
+        .filter_map(|(_, dir)| chip(dir))
+        .collect()
+}
@@ -7,14 +7,31 @@
 //! Also you can get a snapshot of your current hardware and system info
 //! It is meant to monitor a system so the performance is the priority. You can probe every second
 //! that it will not be harmful
+mod board;
+mod cpu;
+mod dmi;
+mod fingerprint;
+mod handles;
+mod hwmon;
 mod machine;
 mod model;
+mod modules;
 mod monitor;
+mod numa;
+mod oom;
+mod os;
+mod pci;
+mod power_supply;
+mod rapl;
+mod raspberry;
+mod sysfs;
+mod thermal;
+mod usb;
+mod virtualization;
 
-#[cfg(feature = "v4l")]
 pub mod camera;
 
 pub use machine::Machine;
-pub use model::{Disk, DiskUsage, Process, GraphicsProcessUtilization, SystemStatus, GraphicsUsage, Processor, GraphicCard, SystemInfo, Camera, NvidiaInfo};
+pub use model::{Disk, DiskUsage, Process, ResourceLimit, GraphicsProcessUtilization, SystemStatus, LoadAverage, VmActivity, GraphicsUsage, Processor, GraphicCard, SystemInfo, Camera, CameraCapabilities, CameraFormat, CameraMode, CameraSizeRange, CameraControl, DeviceUser, NvidiaInfo, Pressure, PressureStats, PressureStatus, CgroupPressure, OomRisk, OomCandidate, ThermalZone, TripPoint, CoolingDevice, SensorChip, SensorReading, CoreFrequency, CpuTopology, LogicalCpu, CpuCache, NumaNode, NumaMemory, ProcessNuma, NumaPages, PowerSupply, RaplZone, ProcessPower, RaspberryStatus, ThrottleFlags, BoardInfo, DmiInfo, Fingerprint, FingerprintSource, Virtualization, OsRelease, KernelInfo, KernelModule, DeviceDriver, PciDevice, UsbDevice};
 
 
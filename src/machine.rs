@@ -3,17 +3,31 @@ use sysinfo::{DiskExt, CpuExt, System, SystemExt};
 use nvml_wrapper::Nvml;
 use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
 use log::{debug, info};
-use crate::model::{SystemInfo, Processor, Disk, GraphicCard, GraphicsUsage, GraphicsProcessUtilization, SystemStatus, Process, Camera, NvidiaInfo};
-use crate::monitor::Monitor;
-use std::path::Path;
-
-#[cfg(feature = "v4l")]
+use crate::model::{SystemInfo, Processor, Disk, GraphicCard, GraphicsUsage, GraphicsProcessUtilization, SystemStatus, Process, NvidiaInfo, OomRisk, PressureStatus, VmActivity, ThermalZone, CoreFrequency, NumaMemory, ProcessNuma, PowerSupply, RaplZone, ProcessPower, RaspberryStatus, Fingerprint, KernelModule, DeviceDriver, DeviceUser};
+use crate::oom;
+use crate::thermal;
+use crate::hwmon;
+use crate::cpu;
+use crate::numa;
+use crate::power_supply;
+use crate::sysfs;
+use crate::raspberry;
+use crate::board;
+use crate::dmi;
+use crate::fingerprint;
+use crate::virtualization;
+use crate::os;
+use crate::modules;
+use crate::handles;
+use crate::pci;
+use crate::usb;
+use crate::monitor::{self, Monitor};
 use crate::camera::list_cameras;
-
-#[cfg(not(feature = "v4l"))]
-fn list_cameras() -> Vec<Camera> {
-    vec![]
-}
+#[cfg(feature = "v4l")]
+use crate::camera;
+#[cfg(feature = "v4l")]
+use crate::model::CameraCapabilities;
+use std::path::Path;
 
 /// Represents a machine. Currently you can monitor global CPU/Memory usage, processes CPU usage and the
 /// Nvidia GPU usage. You can also retrieve information about CPU, disks...
@@ -22,6 +36,12 @@ pub struct Machine {
     nvml: Option<nvml_wrapper::Nvml>,
 }
 
+impl Default for Machine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 
 impl Machine {
     /// Creates a new instance of Machine. If not graphic card it will warn about it but not an error
@@ -43,7 +63,7 @@ impl Machine {
         };
         Machine{
             monitor: Monitor::new(),
-            nvml: nvml
+            nvml
         }
     }
     
@@ -51,7 +71,7 @@ impl Machine {
     /// Example
     /// ```
     /// use machine_info::Machine;
-    /// let m = Machine::new();
+    /// let mut m = Machine::new();
     /// println!("{:?}", m.system_info())
     /// ```
     pub fn system_info(& mut self) -> SystemInfo {
@@ -110,31 +130,37 @@ impl Machine {
             None
         };
         
-        // Getting the model
-        let model_path = Path::new("/sys/firmware/devicetree/base/model");
-        let model = if model_path.exists() {
-            Some(std::fs::read_to_string(model_path).unwrap())
-        } else {
-            None
-        };
-        
+
         let vaapi = Path::new("/dev/dri/renderD128").exists();
+        let dmi = dmi::dmi();
+        let model = board::model().or_else(|| dmi.as_ref().and_then(|dmi| dmi.product_name.clone()));
 
         SystemInfo {
             os_name: sys.name().unwrap(),
             kernel_version: sys.kernel_version().unwrap(),
             os_version: sys.os_version().unwrap(),
             distribution: sys.distribution_id(),
+            os_release: os::os_release(),
+            kernel: os::kernel(),
+            boot_time: monitor::boot_time().unwrap_or(0),
             hostname: sys.host_name().unwrap(),
             memory: sys.total_memory(),
             nvidia,
             vaapi,
             processor,
             total_processors: sys.cpus().len(),
+            topology: cpu::topology(),
+            numa_nodes: numa::nodes(),
             graphics: cards,
+            pci_devices: pci::pci_devices(),
+            usb_devices: usb::usb_devices(),
             disks,
             cameras: list_cameras(),
-            model
+            model,
+            board: board::board(),
+            dmi,
+            virtualization: virtualization::virtualization(),
+            sensors: hwmon::sensors()
         }
     }
 
@@ -161,9 +187,8 @@ impl Machine {
             for n in 0..nvml.device_count().unwrap() {
                 let device = nvml.device_by_index(n).unwrap();
                 let mut processes = Vec::new();
-                let stats = device.process_utilization_stats(None);
-                if stats.is_ok() {
-                    for p in stats.unwrap() {
+                if let Ok(stats) = device.process_utilization_stats(None) {
+                    for p in stats {
                         processes.push(GraphicsProcessUtilization{
                             pid: p.pid,
                             gpu: p.sm_util,
@@ -195,11 +220,11 @@ impl Machine {
     /// To calculate the CPU usage of a process we have to keep track in time the process so first we have to register the process.
     /// You need to know the PID of your process and use it as parameters. In case you provide an invalid PID it will return error
     /// Example
-    /// ```
+    /// ```no_run
     /// use machine_info::Machine;
-    /// let m = Machine::new();
+    /// let mut m = Machine::new();
     /// let process_pid = 3218;
-    /// m.track_process(process_pid)
+    /// m.track_process(process_pid).unwrap();
     /// ```
     pub fn track_process(&mut self, pid: i32) -> Result<()>{
         self.monitor.track_process(pid)
@@ -208,27 +233,28 @@ impl Machine {
     /// Once we dont need to track a process it is recommended to not keep using resources on it. You should know the PID of your process.
     /// If the PID was not registered before, it will just do nothing
     /// Example
-    /// ```
+    /// ```no_run
     /// use machine_info::Machine;
-    /// let m = Machine::new();
+    /// let mut m = Machine::new();
     /// let process_pid = 3218;
-    /// m.track_process(process_pid)
-    /// m.untrack_process(process_pid)
+    /// m.track_process(process_pid).unwrap();
+    /// m.untrack_process(process_pid);
     /// ```
     pub fn untrack_process(&mut self, pid: i32) {
         self.monitor.untrack_process(pid);
     }
 
     /// The CPU usage of all tracked processes since the last call. So if you call it every 10 seconds, you will
-    /// get the CPU usage during the last 10 seconds. More calls will make the value more accurate but also more expensive
+    /// get the CPU usage during the last 10 seconds. More calls will make the value more accurate but also more expensive.
+    /// It also reports the open files, threads and virtual memory of every process against its resource limits
     /// Example
-    /// ```
+    /// ```no_run
     /// use machine_info::Machine;
     /// use std::{thread, time};
     /// 
-    /// let m = Machine::new();
-    /// m.track_process(3218)
-    /// m.track_process(4467)
+    /// let mut m = Machine::new();
+    /// m.track_process(3218).unwrap();
+    /// m.track_process(4467).unwrap();
     /// loop {   
     ///   let status = m.processes_status();
     ///   println!("{:?}", status);
@@ -237,19 +263,20 @@ impl Machine {
     /// 
     /// ```
     pub fn processes_status(& mut self) -> Vec<Process> {
-        self.monitor.next_processes().iter().map(|(pid, cpu)| Process{pid:*pid, cpu:*cpu}).collect::<Vec<Process>>()
+        self.monitor.next_processes()
     }
 
     /// The CPU and memory usage. For the CPU, it is the same as for `processes_status`. For the memory it returs the amount
-    /// a this moment
+    /// a this moment. The load average, tasks and /proc/stat counters are also reported, the counters as rates per second
+    /// since the last call. It includes the readings of the hardware sensors (temperatures, fans, voltages...)
     /// Example
-    /// ```
+    /// ```no_run
     /// use machine_info::Machine;
     /// use std::{thread, time};
     /// 
-    /// let m = Machine::new();
-    /// m.track_process(3218)
-    /// m.track_process(4467)
+    /// let mut m = Machine::new();
+    /// m.track_process(3218).unwrap();
+    /// m.track_process(4467).unwrap();
     /// loop {   
     ///   let status = m.system_status();
     ///   println!("{:?}", status);
@@ -258,11 +285,304 @@ impl Machine {
     /// 
     /// ```
     pub fn system_status(& mut self) -> Result<SystemStatus> {
-        let (cpu, memory) = self.monitor.next()?;
-        Ok(SystemStatus {
-            memory,
-            cpu,
-        })
+        self.monitor.next()
+    }
+
+    /// Risk of running out of memory. It reports the available memory, swap usage and memory pressure
+    /// plus the `candidates` processes the kernel would kill first, the first one being the next victim
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// let risk = m.oom_risk(5).unwrap();
+    /// println!("{:?}", risk.candidates.first());
+    /// ```
+    pub fn oom_risk(&self, candidates: usize) -> Result<OomRisk> {
+        oom::oom_risk(candidates)
+    }
+
+    /// Tracks the pressure stall information of a cgroup (v2 only). The path is the one shown in
+    /// /proc/<pid>/cgroup. It returns error if the cgroup does not exist
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// let mut m = Machine::new();
+    /// m.track_cgroup("/system.slice/docker.service").unwrap();
+    /// ```
+    pub fn track_cgroup(&mut self, path: &str) -> Result<()> {
+        self.monitor.track_cgroup(path)
+    }
+
+    /// Stops tracking a cgroup. If it was not tracked it will just do nothing
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// let mut m = Machine::new();
+    /// m.track_cgroup("/system.slice/docker.service").unwrap();
+    /// m.untrack_cgroup("/system.slice/docker.service");
+    /// ```
+    pub fn untrack_cgroup(&mut self, path: &str) {
+        self.monitor.untrack_cgroup(path);
+    }
+
+    /// The pressure stall information (PSI) of the CPU, memory and I/O for the system and the tracked cgroups.
+    /// Unlike the usage percentages, it tells whether tasks are being delayed waiting for a resource. The stall
+    /// deltas are measured since the last call
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// use std::{thread, time};
+    /// 
+    /// let mut m = Machine::new();
+    /// loop {
+    ///   let pressure = m.pressure_status();
+    ///   println!("{:?}", pressure.memory);
+    ///   thread::sleep(time::Duration::from_millis(1000));
+    /// }
+    /// ```
+    pub fn pressure_status(&mut self) -> PressureStatus {
+        self.monitor.next_pressure()
+    }
+
+    /// Virtual memory activity (page faults, swapping, paging and OOM kills) as rates per second since
+    /// the last call. Use it to detect swap thrashing, which the memory usage cannot show
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// use std::{thread, time};
+    /// 
+    /// let mut m = Machine::new();
+    /// loop {
+    ///   let activity = m.vm_activity().unwrap();
+    ///   println!("{:?}", activity);
+    ///   thread::sleep(time::Duration::from_millis(1000));
+    /// }
+    /// ```
+    pub fn vm_activity(&mut self) -> Result<VmActivity> {
+        self.monitor.next_vmstat()
+    }
+
+    /// The thermal zones of the machine (CPU, SoC, ACPI...) with the current temperature, trip points
+    /// and the state of their cooling devices
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for zone in m.thermal_status() {
+    ///   println!("{} {:?}", zone.kind, zone.temperature);
+    /// }
+    /// ```
+    pub fn thermal_status(&self) -> Vec<ThermalZone> {
+        thermal::thermal_zones()
+    }
+
+    /// The live frequency of every CPU with the scaling limits, governor and the thermal throttle counters.
+    /// Compare `current` with `hardware_max` or watch the counters to know when the machine throttles
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for core in m.frequency_status() {
+    ///   println!("cpu{} {:?} MHz {:?}", core.cpu, core.current, core.governor);
+    /// }
+    /// ```
+    pub fn frequency_status(&self) -> Vec<CoreFrequency> {
+        cpu::frequencies()
+    }
+
+    /// Memory usage and allocation hit/miss counters of every NUMA node. Empty if the kernel has no NUMA support
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for node in m.numa_status() {
+    ///   println!("node{} {} free of {}", node.node, node.free, node.total);
+    /// }
+    /// ```
+    pub fn numa_status(&self) -> Vec<NumaMemory> {
+        numa::memory_status()
+    }
+
+    /// The NUMA nodes where the memory of every tracked process is placed. Reading the placement
+    /// walks all the mappings of the process so it is more expensive than `processes_status`
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// let mut m = Machine::new();
+    /// m.track_process(3218).unwrap();
+    /// println!("{:?}", m.processes_numa());
+    /// ```
+    pub fn processes_numa(&self) -> Vec<ProcessNuma> {
+        self.monitor.numa_processes()
+    }
+
+    /// The status of the power supplies: whether the AC is online and the capacity, charge state,
+    /// voltage, current, energy and estimated time to empty of the batteries and UPS
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for supply in m.power_status() {
+    ///   println!("{} {:?} {:?}%", supply.name, supply.status, supply.capacity);
+    /// }
+    /// ```
+    pub fn power_status(&self) -> Vec<PowerSupply> {
+        power_supply::power_supplies()
+    }
+
+    /// The power used by the RAPL domains (package, core, uncore, dram) since the last call. The first
+    /// call has no previous sample so the power is None. Since Linux 5.10 it requires root
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// use std::{thread, time};
+    /// 
+    /// let mut m = Machine::new();
+    /// loop {
+    ///   for zone in m.energy_status() {
+    ///     println!("{} {:?} W", zone.name, zone.power);
+    ///   }
+    ///   thread::sleep(time::Duration::from_millis(1000));
+    /// }
+    /// ```
+    pub fn energy_status(&mut self) -> Vec<RaplZone> {
+        self.monitor.next_energy()
+    }
+
+    /// Estimates the power used by every process from the package power and its CPU usage. Each process
+    /// gets the share of the package power equal to its share of the total CPU capacity, so the idle power
+    /// is not attributed. Use samples taken over the same period
+    /// Example
+    /// ```no_run
+    /// use machine_info::Machine;
+    /// use std::{thread, time};
+    /// 
+    /// let mut m = Machine::new();
+    /// m.track_process(3218).unwrap();
+    /// loop {
+    ///   let zones = m.energy_status();
+    ///   let processes = m.processes_status();
+    ///   println!("{:?}", m.processes_power(&zones, &processes));
+    ///   thread::sleep(time::Duration::from_millis(1000));
+    /// }
+    /// ```
+    pub fn processes_power(&self, zones: &[RaplZone], processes: &[Process]) -> Vec<ProcessPower> {
+        // Subzones (core, uncore, dram inside a package) are already included in the package
+        let package = zones.iter()
+            .filter(|zone| zone.name.starts_with("package"))
+            .filter_map(|zone| zone.power)
+            .sum::<f64>();
+        let cpus = sysfs::read_cpu_list("/sys/devices/system/cpu/online").len().max(1) as f64;
+
+        processes.iter().map(|process| ProcessPower {
+            pid: process.pid,
+            power: package * process.cpu / (100.0 * cpus)
+        }).collect()
+    }
+
+    /// Undervoltage and throttling status of a Raspberry Pi, decoded from the firmware get_throttled
+    /// bitmask, and the SoC temperature. It returns None if the machine is not a Raspberry Pi
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// if let Some(status) = m.raspberry_status() {
+    ///   if status.occurred.map(|flags| flags.under_voltage).unwrap_or(false) {
+    ///     println!("Undervoltage detected since boot, check the power supply");
+    ///   }
+    /// }
+    /// ```
+    pub fn raspberry_status(&self) -> Option<RaspberryStatus> {
+        raspberry::status()
+    }
+
+    /// A stable identifier of the machine, unlike the hostname. It is the SHA-256 of the available sources:
+    /// /etc/machine-id, the DMI product UUID and serial, the devicetree serial number and the permanent MAC
+    /// addresses of the physical network interfaces. The report tells which sources contributed.
+    ///
+    /// Stability policy: the id does not change across reboots, hostname or IP changes and crate updates with
+    /// the same `version`. It changes if a contributing source changes: reinstalling the OS (new machine-id),
+    /// replacing the motherboard or a built-in network card. Only the permanent (burnt in) addresses of
+    /// network cards on internal buses (PCI, SoC) are used, so plugging or unplugging a USB network adapter,
+    /// MAC randomization, bonding or setting the address does not change it. If the driver does not report
+    /// the permanent address, the current one is used only if it was never set, and never for Wi-Fi and bond
+    /// slaves. The DMI UUID and serial are readable only by root, so always compute it with the same privileges.
+    /// It returns error if no source is available
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// if let Ok(fingerprint) = m.fingerprint() {
+    ///   println!("{} {:?}", fingerprint.id, fingerprint.sources);
+    /// }
+    /// ```
+    pub fn fingerprint(&self) -> Result<Fingerprint> {
+        fingerprint::fingerprint()
+    }
+
+    /// The loaded kernel modules with their size, references, dependent modules and state
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// let loaded = m.kernel_modules().iter().any(|module| module.name == "uvcvideo");
+    /// println!("uvcvideo loaded: {}", loaded);
+    /// ```
+    pub fn kernel_modules(&self) -> Vec<KernelModule> {
+        modules::kernel_modules()
+    }
+
+    /// The driver and module bound to every PCI device, USB device and interface and video device
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for device in m.device_drivers() {
+    ///   println!("{} {} {:?}", device.subsystem, device.device, device.driver);
+    /// }
+    /// ```
+    pub fn device_drivers(&self) -> Vec<DeviceDriver> {
+        modules::device_drivers()
+    }
+
+    /// Processes holding open device nodes. The path selects them:
+    /// - A node like /dev/video0 matches only that node (not /dev/video10), even if it was removed (like an
+    ///   unplugged camera still held open). Links are resolved
+    /// - A directory like /dev/dri matches every node inside it, so every GPU
+    /// - A path that does not exist and does not end in a number matches the nodes starting with it, so
+    ///   /dev/nvidia matches nvidia0, nvidiactl, nvidia-uvm and nvidia-modeset
+    ///
+    /// Use it to find who keeps a camera busy or which processes use a GPU without NVML. Every call scans
+    /// /proc once. The handles of other users' processes are visible only to root
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for user in m.device_users("/dev/dri").iter().chain(m.device_users("/dev/nvidia").iter()) {
+    ///   println!("{} {} {:?}", user.pid, user.name, user.devices);
+    /// }
+    /// ```
+    pub fn device_users(&self, path: &str) -> Vec<DeviceUser> {
+        handles::device_users(path)
+    }
+
+    /// Capabilities of every video node of the attached cameras: formats, frame sizes, frame rates and
+    /// controls. Nodes that cannot be opened are skipped. Only available with the `v4l` feature
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let m = Machine::new();
+    /// for node in m.camera_capabilities().iter().filter(|node| node.capture) {
+    ///   println!("{} {} formats", node.path, node.formats.len());
+    /// }
+    /// ```
+    #[cfg(feature = "v4l")]
+    pub fn camera_capabilities(&self) -> Vec<CameraCapabilities> {
+        list_cameras()
+            .iter()
+            .flat_map(|camera| camera.nodes.iter())
+            .filter_map(|node| camera::capabilities(node).ok())
+            .collect()
     }
 
 }
\ No newline at end of file
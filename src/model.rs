@@ -1,4 +1,5 @@
 use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
 
 /// System status
 #[derive(Deserialize, Serialize, Debug)]
@@ -20,7 +21,144 @@ pub struct Process {
     pub pid: i32,
     /// Cpu used as percentage
     pub cpu: f64,
-    
+    /// Open file descriptors against RLIMIT_NOFILE. None if the descriptors cannot be read (not our process)
+    pub open_files: Option<ResourceLimit>,
+    /// Threads of all the processes of the real user of the process against RLIMIT_NPROC, because the
+    /// limit is per user. Root processes are not limited
+    pub threads: ResourceLimit,
+    /// Virtual memory in bytes against RLIMIT_AS
+    pub virtual_memory: ResourceLimit,
+    /// Badness score used by the kernel to choose the process to kill when out of memory
+    pub oom_score: Option<i32>,
+    /// User adjustment of the oom score, from -1000 (never kill) to 1000
+    pub oom_score_adj: Option<i32>,
+}
+
+/// Usage of a resource compared with the soft limit applied to a process
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLimit {
+    /// Current usage
+    pub used: u64,
+    /// Soft limit. None when unlimited
+    pub limit: Option<u64>,
+}
+
+impl ResourceLimit {
+    /// Usage as a fraction of the limit (0.8 means 80% of the limit). None when unlimited
+    /// Example
+    /// ```
+    /// use machine_info::ResourceLimit;
+    /// let files = ResourceLimit { used: 900, limit: Some(1024) };
+    /// assert!(files.ratio().unwrap() > 0.8);
+    /// ```
+    pub fn ratio(&self) -> Option<f64> {
+        match self.limit {
+            Some(0) | None => None,
+            Some(limit) => Some(self.used as f64 / limit as f64)
+        }
+    }
+}
+
+/// Pressure stall information of a resource
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Pressure {
+    /// Time where at least some tasks were stalled
+    pub some: PressureStats,
+    /// Time where all non idle tasks were stalled. Not reported for the CPU on old kernels
+    pub full: Option<PressureStats>,
+}
+
+/// Stall averages and total stall time
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PressureStats {
+    /// Percentage of time stalled during the last 10 seconds
+    pub avg10: f64,
+    /// Percentage of time stalled during the last 60 seconds
+    pub avg60: f64,
+    /// Percentage of time stalled during the last 300 seconds
+    pub avg300: f64,
+    /// Total stall time in microseconds
+    pub total: u64,
+    /// Stall time in microseconds since the previous sample (since boot for the first one)
+    pub delta: u64,
+}
+
+/// Pressure stall information of the whole system and the tracked cgroups. A resource is None if
+/// the kernel has no PSI support
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PressureStatus {
+    /// Pressure of tasks waiting for a CPU
+    pub cpu: Option<Pressure>,
+    /// Pressure of tasks waiting for memory (reclaim, swap, refaults)
+    pub memory: Option<Pressure>,
+    /// Pressure of tasks waiting for I/O
+    pub io: Option<Pressure>,
+    /// Pressure of every tracked cgroup
+    pub cgroups: Vec<CgroupPressure>,
+}
+
+/// Pressure stall information of a cgroup
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CgroupPressure {
+    /// Cgroup path like /system.slice/docker.service
+    pub path: String,
+    /// Pressure of tasks waiting for a CPU
+    pub cpu: Option<Pressure>,
+    /// Pressure of tasks waiting for memory
+    pub memory: Option<Pressure>,
+    /// Pressure of tasks waiting for I/O
+    pub io: Option<Pressure>,
+}
+
+/// How close the machine is to run out of memory
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OomRisk {
+    /// Total memory in bytes
+    pub memory_total: u64,
+    /// Memory available for new allocations without swapping, in bytes
+    pub memory_available: u64,
+    /// Total swap in bytes
+    pub swap_total: u64,
+    /// Swap used in bytes
+    pub swap_used: u64,
+    /// Memory pressure. None if the kernel has no PSI support
+    pub pressure: Option<Pressure>,
+    /// Processes sorted by the order the OOM killer would pick them
+    pub candidates: Vec<OomCandidate>,
+}
+
+/// Process that could be killed by the OOM killer
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OomCandidate {
+    /// Process identificator
+    pub pid: i32,
+    /// Process name
+    pub name: String,
+    /// Badness score. The highest is killed first
+    pub oom_score: i32,
+    /// User adjustment of the score
+    pub oom_score_adj: i32,
+    /// Resident memory in bytes
+    pub memory: u64,
+}
+
+/// Process holding open a device node
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceUser {
+    /// Process identificator
+    pub pid: i32,
+    /// Process name
+    pub name: String,
+    /// Device nodes opened by the process, like /dev/video0 or /dev/dri/renderD128
+    pub devices: Vec<String>,
 }
 
 /// Graphic card usage by process
@@ -69,6 +207,61 @@ pub struct SystemStatus {
     pub memory: i32,
     /// Total CPU used as percentage
     pub cpu: i32,
+    /// System load average
+    pub load_average: LoadAverage,
+    /// Tasks currently runnable
+    pub running_tasks: u32,
+    /// Tasks (processes and threads) in the system
+    pub total_tasks: u32,
+    /// Processes running on a CPU
+    pub procs_running: u32,
+    /// Processes blocked waiting for I/O
+    pub procs_blocked: u32,
+    /// Context switches per second
+    pub context_switches: f64,
+    /// Interrupts per second
+    pub interrupts: f64,
+    /// Processes created per second
+    pub forks: f64,
+    /// Seconds since boot
+    pub uptime: u64,
+    /// Boot time as seconds since the epoch
+    pub boot_time: u64,
+    /// Hardware sensors readings
+    pub sensors: Vec<SensorChip>,
+}
+
+/// Virtual memory activity as rates per second. High swap in and out rates together mean the
+/// system is thrashing
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VmActivity {
+    /// Page faults solved without disk access
+    pub minor_faults: f64,
+    /// Page faults that required disk access
+    pub major_faults: f64,
+    /// Pages read from swap
+    pub swap_in: f64,
+    /// Pages written to swap
+    pub swap_out: f64,
+    /// Kilobytes paged in from disk
+    pub page_in: f64,
+    /// Kilobytes paged out to disk
+    pub page_out: f64,
+    /// Processes killed by the OOM killer
+    pub oom_kills: f64,
+}
+
+/// Number of jobs in the run queue or waiting for disk I/O averaged over 1, 5 and 15 minutes
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadAverage {
+    /// Last minute average
+    pub one: f64,
+    /// Last 5 minutes average
+    pub five: f64,
+    /// Last 15 minutes average
+    pub fifteen: f64,
 }
 
 /// Summary of the system
@@ -85,14 +278,29 @@ pub struct SystemInfo {
     pub hostname: String,
     /// Distribution id like ubuntu, neon, raspbian...
     pub distribution: String,
+    /// All the fields of /etc/os-release
+    pub os_release: Option<OsRelease>,
+    /// Running kernel details
+    pub kernel: KernelInfo,
+    /// Boot time as seconds since the epoch
+    pub boot_time: u64,
     /// Total memory of the machine
     pub memory: u64,
     /// Microprocessor description
     pub processor: Processor,
     /// Total amount of processors
     pub total_processors: usize,
+    /// Sockets, cores, caches and flags of the processors
+    pub topology: CpuTopology,
+    /// NUMA nodes. Machines without NUMA have a single node, but kernels built without NUMA support (like
+    /// the Raspberry Pi ones) have none, so the list is empty
+    pub numa_nodes: Vec<NumaNode>,
     /// List of graphic cards
     pub graphics: Vec<GraphicCard>,
+    /// Every PCI device (GPUs, NICs, NVMe, accelerators...)
+    pub pci_devices: Vec<PciDevice>,
+    /// USB devices as a tree, the roots are the host controller hubs
+    pub usb_devices: Vec<UsbDevice>,
     /// List of available disks
     pub disks: Vec<Disk>,
     /// List of available cameras
@@ -101,8 +309,166 @@ pub struct SystemInfo {
     pub nvidia: Option<NvidiaInfo>,
     /// If the machine supports vaapi
     pub vaapi: bool,
-    /// Machine model. Some machines has special models like rpi
-    pub model: Option<String>
+    /// Machine model. Some machines has special models like rpi. On x86 it is the DMI product name
+    pub model: Option<String>,
+    /// Board identity decoded from the devicetree (Raspberry Pi, Jetson...)
+    pub board: Option<BoardInfo>,
+    /// Hardware identity from DMI/SMBIOS (x86 machines)
+    pub dmi: Option<DmiInfo>,
+    /// Whether the machine is bare metal, a virtual machine or a container
+    pub virtualization: Virtualization,
+    /// Hardware sensors available
+    pub sensors: Vec<SensorChip>,
+}
+
+/// Identity of a board described by the devicetree
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardInfo {
+    /// Model like "Raspberry Pi 4 Model B Rev 1.4"
+    pub model: String,
+    /// Vendor from the compatible string like raspberrypi or nvidia
+    pub vendor: Option<String>,
+    /// Board family like "Raspberry Pi 4B" or "Jetson Orin Nano". Only for known boards
+    pub family: Option<String>,
+    /// Board revision
+    pub revision: Option<String>,
+    /// Board serial number
+    pub serial: Option<String>,
+    /// System on chip like BCM2711 or tegra210
+    pub soc: Option<String>,
+    /// Board manufacturer
+    pub manufacturer: Option<String>,
+    /// Memory of the board in bytes as stated by the revision
+    pub memory: Option<u64>,
+    /// Devicetree compatible strings, most specific first
+    pub compatible: Vec<String>,
+}
+
+/// Hardware identity from the DMI/SMBIOS tables. Fields are None when the vendor did not fill them or
+/// they are readable only by root (serial numbers and UUID)
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DmiInfo {
+    /// System manufacturer
+    pub sys_vendor: Option<String>,
+    /// System model like "PowerEdge R740"
+    pub product_name: Option<String>,
+    /// System version
+    pub product_version: Option<String>,
+    /// System serial number
+    pub product_serial: Option<String>,
+    /// System UUID
+    pub product_uuid: Option<String>,
+    /// Motherboard manufacturer
+    pub board_vendor: Option<String>,
+    /// Motherboard model
+    pub board_name: Option<String>,
+    /// Motherboard version
+    pub board_version: Option<String>,
+    /// BIOS vendor
+    pub bios_vendor: Option<String>,
+    /// BIOS version
+    pub bios_version: Option<String>,
+    /// BIOS release date
+    pub bios_date: Option<String>,
+    /// Chassis type like Desktop, Laptop or Rack Mount Chassis
+    pub chassis_type: Option<String>,
+}
+
+/// Virtualization environment. Both are None on bare metal and both can be set, like a container
+/// running in a virtual machine
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Virtualization {
+    /// Hypervisor: kvm, qemu, vmware, hyperv, xen, virtualbox, amazon, google, parallels or unknown. None on
+    /// bare metal and in a Xen dom0, which is the host
+    pub hypervisor: Option<String>,
+    /// Container: docker, podman, lxc, kubernetes, wsl or the name given by the container manager
+    pub container: Option<String>,
+}
+
+impl Virtualization {
+    /// Whether the machine is not virtualized at all
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let mut m = Machine::new();
+    /// println!("Bare metal: {}", m.system_info().virtualization.is_bare_metal());
+    /// ```
+    pub fn is_bare_metal(&self) -> bool {
+        self.hypervisor.is_none() && self.container.is_none()
+    }
+}
+
+/// Stable identifier of the machine
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Fingerprint {
+    /// SHA-256 of the sources as hexadecimal
+    pub id: String,
+    /// Version of the algorithm used to build the id
+    pub version: u32,
+    /// Sources considered and whether they contributed to the id
+    pub sources: Vec<FingerprintSource>,
+}
+
+/// Identity source of the fingerprint
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FingerprintSource {
+    /// Source name: machine-id, dmi-uuid, dmi-serial, devicetree-serial or mac
+    pub name: String,
+    /// Whether the source was available and included in the id
+    pub used: bool,
+}
+
+/// Operating system identification from /etc/os-release
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OsRelease {
+    /// Distribution id like ubuntu, debian or fedora
+    pub id: String,
+    /// Distributions this one is derived from, like debian for ubuntu
+    pub id_like: Vec<String>,
+    /// Distribution name
+    pub name: Option<String>,
+    /// Distribution name with the version
+    pub pretty_name: Option<String>,
+    /// Version like "22.04.3 LTS (Jammy Jellyfish)"
+    pub version: Option<String>,
+    /// Version number like 22.04
+    pub version_id: Option<String>,
+    /// Version codename like jammy
+    pub version_codename: Option<String>,
+    /// Variant like Server Edition
+    pub variant: Option<String>,
+    /// Variant id like server
+    pub variant_id: Option<String>,
+    /// Build id of the image
+    pub build_id: Option<String>,
+    /// Every field of the file, including the ones above
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Running kernel
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct KernelInfo {
+    /// Kernel release like 5.15.0-91-generic
+    pub release: String,
+    /// Major version
+    pub major: u32,
+    /// Minor version
+    pub minor: u32,
+    /// Patch version
+    pub patch: u32,
+    /// Text after the version like 91-generic or v8+
+    pub flavor: Option<String>,
+    /// Machine architecture like x86_64 or aarch64
+    pub architecture: String,
+    /// Kernel command line
+    pub command_line: String,
 }
 
 /// Information about microprocessor
@@ -117,6 +483,155 @@ pub struct Processor {
     pub brand: String
 }
 
+/// Layout of the processors of the machine
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTopology {
+    /// Physical processor packages
+    pub sockets: usize,
+    /// Physical cores of all the sockets
+    pub physical_cores: usize,
+    /// Hardware threads per core (SMT). 1 if SMT is not available or disabled
+    pub threads_per_core: usize,
+    /// Online logical CPUs
+    pub online: Vec<u32>,
+    /// Offline logical CPUs
+    pub offline: Vec<u32>,
+    /// Placement of every online logical CPU
+    pub cpus: Vec<LogicalCpu>,
+    /// Caches of the processors. A cache shared by several CPUs is listed once
+    pub caches: Vec<CpuCache>,
+    /// CPU flags like avx2, avx512f or neon (asimd on arm64)
+    pub flags: Vec<String>,
+}
+
+impl CpuTopology {
+    /// Whether the processor supports a feature flag
+    /// Example
+    /// ```
+    /// use machine_info::Machine;
+    /// let mut m = Machine::new();
+    /// let topology = m.system_info().topology;
+    /// println!("AVX2 available: {}", topology.has_flag("avx2"));
+    /// ```
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+}
+
+/// Placement of a logical CPU
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LogicalCpu {
+    /// Logical CPU number
+    pub cpu: u32,
+    /// Physical package id
+    pub socket: u32,
+    /// Core id inside the package
+    pub core: u32,
+    /// NUMA node the CPU belongs to
+    pub node: Option<u32>,
+    /// Logical CPUs sharing the same core (SMT siblings), including this one
+    pub siblings: Vec<u32>,
+}
+
+/// Processor cache
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCache {
+    /// Cache level (1, 2, 3)
+    pub level: u32,
+    /// Cache type: Data, Instruction or Unified
+    pub kind: String,
+    /// Size in bytes
+    pub size: Option<u64>,
+    /// Logical CPUs sharing the cache
+    pub shared_cpus: Vec<u32>,
+}
+
+/// NUMA node of the machine
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NumaNode {
+    /// Node id
+    pub node: u32,
+    /// CPUs of the node
+    pub cpus: Vec<u32>,
+    /// Memory attached to the node in bytes
+    pub memory: u64,
+}
+
+/// Memory usage of a NUMA node. The counters are pages allocated since boot
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NumaMemory {
+    /// Node id
+    pub node: u32,
+    /// Total memory in bytes
+    pub total: u64,
+    /// Free memory in bytes
+    pub free: u64,
+    /// Used memory in bytes
+    pub used: u64,
+    /// Pages allocated on the intended node
+    pub numa_hit: u64,
+    /// Pages allocated on this node although another one was intended
+    pub numa_miss: u64,
+    /// Pages intended for this node but allocated on another one
+    pub numa_foreign: u64,
+    /// Pages allocated on this node by a process running on it
+    pub local_node: u64,
+    /// Pages allocated on this node by a process running on another one
+    pub other_node: u64,
+}
+
+/// NUMA placement of the memory of a tracked process
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessNuma {
+    /// Process identificator
+    pub pid: i32,
+    /// Memory of the process on every node
+    pub nodes: Vec<NumaPages>,
+}
+
+/// Memory placed on a NUMA node
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NumaPages {
+    /// Node id
+    pub node: u32,
+    /// Memory in bytes
+    pub memory: u64,
+}
+
+/// Live frequency of a CPU. The values are None when the kernel does not expose them (no cpufreq driver,
+/// virtual machines...)
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CoreFrequency {
+    /// Logical CPU number
+    pub cpu: u32,
+    /// Current frequency in MHz
+    pub current: Option<u64>,
+    /// Minimum frequency allowed by the scaling policy in MHz
+    pub min: Option<u64>,
+    /// Maximum frequency allowed by the scaling policy in MHz
+    pub max: Option<u64>,
+    /// Minimum frequency supported by the hardware in MHz
+    pub hardware_min: Option<u64>,
+    /// Maximum frequency supported by the hardware in MHz
+    pub hardware_max: Option<u64>,
+    /// Scaling governor like performance, powersave, schedutil...
+    pub governor: Option<String>,
+    /// Energy performance preference like performance, balance_power... (intel_pstate and amd-pstate)
+    pub energy_performance_preference: Option<String>,
+    /// Times the core has been throttled because of the temperature
+    pub core_throttle_count: Option<u64>,
+    /// Times the package has been throttled because of the temperature
+    pub package_throttle_count: Option<u64>,
+}
+
 /// Information about a graphic card
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -133,6 +648,262 @@ pub struct GraphicCard {
     pub temperature: u32
 }
 
+/// Thermal zone like the CPU package or the SoC
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ThermalZone {
+    /// Zone name like thermal_zone0
+    pub name: String,
+    /// Zone type like x86_pkg_temp, cpu-thermal, acpitz...
+    pub kind: String,
+    /// Current temperature in Celsius. None if the zone is disabled
+    pub temperature: Option<f64>,
+    /// Temperatures where the kernel takes actions
+    pub trip_points: Vec<TripPoint>,
+    /// Cooling devices bound to the zone
+    pub cooling_devices: Vec<CoolingDevice>,
+}
+
+/// Temperature where the kernel takes an action
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TripPoint {
+    /// Trip type: active, passive, hot or critical
+    pub kind: String,
+    /// Temperature in Celsius
+    pub temperature: f64,
+    /// Hysteresis in Celsius
+    pub hysteresis: Option<f64>,
+}
+
+/// Device used to cool down a thermal zone like a fan or the CPU frequency
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CoolingDevice {
+    /// Device name like cooling_device0
+    pub name: String,
+    /// Device type like Processor, Fan, cpufreq-cpu0...
+    pub kind: String,
+    /// Current cooling state. 0 means no cooling
+    pub current_state: u64,
+    /// Maximum cooling state
+    pub max_state: u64,
+    /// Trip point of the zone that activates the device
+    pub trip_point: Option<usize>,
+}
+
+/// Hardware monitoring chip like coretemp, k10temp, nvme or nct6775
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SensorChip {
+    /// Chip name
+    pub name: String,
+    /// Temperatures in Celsius
+    pub temperatures: Vec<SensorReading>,
+    /// Fan speeds in RPM
+    pub fans: Vec<SensorReading>,
+    /// Voltages in volts
+    pub voltages: Vec<SensorReading>,
+    /// Currents in amperes
+    pub currents: Vec<SensorReading>,
+    /// Power in watts
+    pub power: Vec<SensorReading>,
+}
+
+/// Reading of a sensor channel with its thresholds
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SensorReading {
+    /// Channel label like "Package id 0" or "Composite". The channel name (temp1) if there is no label
+    pub label: String,
+    /// Current value
+    pub value: f64,
+    /// Minimum threshold
+    pub min: Option<f64>,
+    /// Maximum threshold
+    pub max: Option<f64>,
+    /// Critical threshold
+    pub crit: Option<f64>,
+}
+
+/// Power supply like an AC adapter, a battery or an UPS
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerSupply {
+    /// Supply name like AC, BAT0...
+    pub name: String,
+    /// Supply type: Mains, Battery, UPS, USB...
+    pub kind: String,
+    /// Whether the supply is connected. Only reported by adapters
+    pub online: Option<bool>,
+    /// Battery status: Charging, Discharging, Full, Not charging or Unknown
+    pub status: Option<String>,
+    /// Battery capacity as percentage
+    pub capacity: Option<u8>,
+    /// Voltage in volts
+    pub voltage: Option<f64>,
+    /// Current in amperes
+    pub current: Option<f64>,
+    /// Power in watts
+    pub power: Option<f64>,
+    /// Energy stored in watt hours
+    pub energy: Option<f64>,
+    /// Energy stored when full in watt hours
+    pub energy_full: Option<f64>,
+    /// Estimated seconds until the battery is empty. Only while discharging
+    pub time_to_empty: Option<u64>,
+}
+
+/// Power consumption of a RAPL domain
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RaplZone {
+    /// Zone id like intel-rapl:0 or intel-rapl:0:1 for its subzones
+    pub id: String,
+    /// Domain: package-N, core, uncore, dram or psys
+    pub name: String,
+    /// Average power in watts since the last sample. None for the first one
+    pub power: Option<f64>,
+}
+
+/// Estimated power used by a process
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessPower {
+    /// Process identificator
+    pub pid: i32,
+    /// Power in watts
+    pub power: f64,
+}
+
+/// Health of a Raspberry Pi as reported by the firmware
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RaspberryStatus {
+    /// Board model like "Raspberry Pi 4 Model B Rev 1.4"
+    pub model: String,
+    /// Raw get_throttled bitmask. None if the firmware does not expose it
+    pub throttled: Option<u32>,
+    /// Conditions active right now
+    pub current: Option<ThrottleFlags>,
+    /// Conditions that happened since boot (sticky flags)
+    pub occurred: Option<ThrottleFlags>,
+    /// SoC temperature in Celsius
+    pub temperature: Option<f64>,
+}
+
+/// Throttling conditions of a Raspberry Pi
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ThrottleFlags {
+    /// The power supply voltage is below 4.63V
+    pub under_voltage: bool,
+    /// The ARM frequency is capped
+    pub arm_frequency_capped: bool,
+    /// The processor is throttled
+    pub throttled: bool,
+    /// The soft temperature limit is active
+    pub soft_temperature_limit: bool,
+}
+
+/// Kernel module loaded
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct KernelModule {
+    /// Module name like uvcvideo or nvidia
+    pub name: String,
+    /// Memory used by the module in bytes
+    pub size: u64,
+    /// Number of references to the module
+    pub references: u32,
+    /// Modules depending on this one
+    pub used_by: Vec<String>,
+    /// State: Live, Loading or Unloading
+    pub state: String,
+}
+
+/// Driver bound to a device
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceDriver {
+    /// Subsystem of the device: pci, usb or video4linux
+    pub subsystem: String,
+    /// Device name like 0000:01:00.0, 1-1:1.0 or video0
+    pub device: String,
+    /// Bound driver like nvidia, nouveau or uvcvideo. None if no driver is bound
+    pub driver: Option<String>,
+    /// Module of the driver. None if the driver is built into the kernel
+    pub module: Option<String>,
+}
+
+/// PCI device. The names are None if there is no pci.ids database installed
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PciDevice {
+    /// Address like 0000:01:00.0
+    pub address: String,
+    /// Class code: base class, subclass and programming interface like 0x030000
+    pub class: u32,
+    /// Class name like "VGA compatible controller"
+    pub class_name: Option<String>,
+    /// Vendor id like 0x10de
+    pub vendor_id: u16,
+    /// Device id
+    pub device_id: u16,
+    /// Subsystem vendor id
+    pub subsystem_vendor_id: Option<u16>,
+    /// Subsystem device id
+    pub subsystem_device_id: Option<u16>,
+    /// Vendor name like "NVIDIA Corporation"
+    pub vendor_name: Option<String>,
+    /// Device name
+    pub device_name: Option<String>,
+    /// Subsystem name, usually the board of the manufacturer
+    pub subsystem_name: Option<String>,
+    /// Bound driver
+    pub driver: Option<String>,
+    /// NUMA node the device is attached to
+    pub numa_node: Option<u32>,
+    /// Current PCIe link speed like "8.0 GT/s PCIe"
+    pub link_speed: Option<String>,
+    /// Current PCIe link width (lanes)
+    pub link_width: Option<u32>,
+}
+
+/// USB device with the devices connected to it if it is a hub
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbDevice {
+    /// Device name like usb1, 1-1 or 1-1.2
+    pub name: String,
+    /// Bus number
+    pub bus: u32,
+    /// Device number in the bus, as in /dev/bus/usb/BBB/DDD
+    pub device_number: u32,
+    /// Port path from the root hub like 1.2. It does not change between reboots
+    pub port_path: String,
+    /// Vendor id like 0x046d
+    pub vendor_id: u16,
+    /// Product id
+    pub product_id: u16,
+    /// Manufacturer string
+    pub manufacturer: Option<String>,
+    /// Product string
+    pub product: Option<String>,
+    /// Serial number
+    pub serial: Option<String>,
+    /// Negotiated speed in Mbps (1.5, 12, 480, 5000...)
+    pub speed: Option<f64>,
+    /// Maximum power draw in mA
+    pub max_power: Option<u32>,
+    /// Driver bound to the device, usually usb or hub
+    pub driver: Option<String>,
+    /// Drivers bound to the interfaces of the device like uvcvideo or usbhid
+    pub interface_drivers: Vec<String>,
+    /// Devices connected to this one
+    pub children: Vec<UsbDevice>,
+}
+
 /// Information about a hard disk
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -151,14 +922,132 @@ pub struct Disk {
     pub size: u64
 }
 
-/// Connected camera information
+/// Connected camera information. A UVC camera, which exposes a capture and a metadata node, is reported
+/// once. Every node of other devices (like multi-input capture cards) is a camera on its own
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Camera {
     /// The camera name
     pub name: String,
-    /// Camera path like /dev/video0
-    pub path: String
+    /// Camera path like /dev/video0. The number can change between reboots
+    pub path: String,
+    /// Persistent link in /dev/v4l/by-id, based on the USB vendor, product and serial
+    pub by_id: Option<String>,
+    /// Persistent link in /dev/v4l/by-path, based on the port the camera is connected to
+    pub by_path: Option<String>,
+    /// Every video node of the camera, the capture one first
+    pub nodes: Vec<String>,
+    /// USB vendor id
+    pub vendor_id: Option<u16>,
+    /// USB product id
+    pub product_id: Option<u16>,
+    /// USB serial number
+    pub serial: Option<String>,
+    /// USB device name like 1-1.2, which is the bus and port path
+    pub bus_path: Option<String>,
+    /// Driver like uvcvideo
+    pub driver: Option<String>,
+}
+
+/// What a video node can do, queried through v4l. Only available with the `v4l` feature
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraCapabilities {
+    /// Video node like /dev/video0
+    pub path: String,
+    /// Driver like uvcvideo
+    pub driver: String,
+    /// Device name reported by the driver
+    pub card: String,
+    /// Bus information like usb-0000:00:14.0-2
+    pub bus: String,
+    /// The node captures video frames. Its formats are listed
+    pub capture: bool,
+    /// The node captures multi-planar video frames, common on ARM ISPs. Their formats are not listed
+    pub multiplanar: bool,
+    /// The node captures metadata (like the UVC metadata node), not frames
+    pub metadata: bool,
+    /// The node supports streaming I/O (mmap or user pointers)
+    pub streaming: bool,
+    /// Supported pixel formats
+    pub formats: Vec<CameraFormat>,
+    /// Controls like brightness, exposure or focus. Empty for nodes that do not capture and for memory to
+    /// memory nodes (codecs, scalers)
+    pub controls: Vec<CameraControl>,
+}
+
+/// Pixel format supported by a camera
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraFormat {
+    /// Four character code like YUYV or MJPG
+    pub fourcc: String,
+    /// Description given by the driver
+    pub description: String,
+    /// The frames are compressed (MJPG, H264...)
+    pub compressed: bool,
+    /// The format is converted by libv4l in software, so it costs CPU
+    pub emulated: bool,
+    /// Frame sizes with their frame rates. For stepwise sizes only the smallest and the biggest are listed
+    pub modes: Vec<CameraMode>,
+    /// Range of sizes when the camera supports any size in steps instead of a list of them
+    pub size_range: Option<CameraSizeRange>,
+}
+
+/// Frame size and the frame rates available with it
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraMode {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Frames per second, the highest first. For stepwise intervals only the limits are listed
+    pub frame_rates: Vec<f64>,
+}
+
+/// Sizes supported by a stepwise or continuous camera
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraSizeRange {
+    /// Minimum width in pixels
+    pub min_width: u32,
+    /// Maximum width in pixels
+    pub max_width: u32,
+    /// Width increment
+    pub step_width: u32,
+    /// Minimum height in pixels
+    pub min_height: u32,
+    /// Maximum height in pixels
+    pub max_height: u32,
+    /// Height increment
+    pub step_height: u32,
+}
+
+/// Camera control
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraControl {
+    /// Control identifier, used to read or set it
+    pub id: u32,
+    /// Control name like Brightness
+    pub name: String,
+    /// Type like Integer, Boolean or Menu
+    pub kind: String,
+    /// Minimum value
+    pub minimum: i64,
+    /// Maximum value
+    pub maximum: i64,
+    /// Step between values
+    pub step: u64,
+    /// Default value
+    pub default: i64,
+    /// It cannot be set
+    pub read_only: bool,
+    /// It has no effect right now, like the exposure time when auto exposure is on
+    pub inactive: bool,
+    /// Options of menu controls
+    pub menu: Vec<String>,
 }
 
 /// Nvidia drivers configuration
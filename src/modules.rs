@@ -0,0 +1,62 @@
+use std::path::Path;
+use crate::model::{KernelModule, DeviceDriver};
+use crate::sysfs::{driver, driver_module};
+
+/// Loaded modules from /proc/modules. Drivers built into the kernel are not listed
+pub fn kernel_modules() -> Vec<KernelModule> {
+    let mut modules = vec![];
+    let content = std::fs::read_to_string("/proc/modules").unwrap_or_default();
+    // name size references used_by state address [taints]
+    for line in content.lines() {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() < 5 {
+            continue;
+        }
+        modules.push(KernelModule {
+            name: fields[0].to_owned(),
+            size: fields[1].parse::<u64>().unwrap_or(0),
+            references: fields[2].parse::<u32>().unwrap_or(0),
+            // Like "nvidia_modeset,nvidia_uvm," or "-" when unused
+            used_by: fields[3].split(',').filter(|name| !name.is_empty() && *name != "-").map(|name| name.to_owned()).collect(),
+            state: fields[4].to_owned()
+        });
+    }
+    modules
+}
+
+fn bus_drivers(drivers: &mut Vec<DeviceDriver>, subsystem: &str) {
+    let dir = Path::new("/sys/bus").join(subsystem).join("devices");
+    let mut devices = std::fs::read_dir(dir).into_iter().flatten().flatten().collect::<Vec<_>>();
+    devices.sort_by_key(|device| device.file_name());
+    for device in devices {
+        let path = device.path();
+        drivers.push(DeviceDriver {
+            subsystem: subsystem.to_owned(),
+            device: device.file_name().to_string_lossy().into_owned(),
+            driver: driver(&path),
+            module: driver_module(&path)
+        });
+    }
+}
+
+/// Driver bound to every PCI device, USB device and interface and video4linux device. Devices
+/// without driver are listed too, so a missing module can be spotted
+pub fn device_drivers() -> Vec<DeviceDriver> {
+    let mut drivers = vec![];
+    bus_drivers(&mut drivers, "pci");
+    bus_drivers(&mut drivers, "usb");
+
+    let mut videos = std::fs::read_dir("/sys/class/video4linux").into_iter().flatten().flatten().collect::<Vec<_>>();
+    videos.sort_by_key(|video| video.file_name());
+    for video in videos {
+        // The driver is bound to the parent device (the USB interface for UVC cameras)
+        let device = video.path().join("device");
+        drivers.push(DeviceDriver {
+            subsystem: "video4linux".to_owned(),
+            device: video.file_name().to_string_lossy().into_owned(),
+            driver: driver(&device),
+            module: driver_module(&device)
+        });
+    }
+    drivers
+}
@@ -1,40 +1,123 @@
 use anyhow::Result;
 use std::fs::File;
 use std::io::{self, BufRead};
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use log::warn;
+use crate::model;
+use crate::oom;
+use crate::hwmon;
+use crate::numa;
+use crate::rapl;
 
 #[derive(Debug)]
 pub struct Monitor {
-    last_cpu: Cpu,
-    last_processes: HashMap<i32, Process>
+    last_stat: Stat,
+    last_vmstat: VmStat,
+    last_processes: HashMap<i32, Process>,
+    // Cgroup path and its directory in the cgroup v2 hierarchy
+    cgroups: Vec<(String, PathBuf)>,
+    // Total some/full stall time of every pressure file
+    last_pressure: HashMap<PathBuf, (u64, u64)>,
+    // Energy counter of every RAPL zone
+    last_energy: HashMap<String, (u64, Instant)>
 }
 
 impl Monitor {
     pub fn new() -> Monitor {
         Monitor {
-            last_cpu: Cpu{values: vec![0;10]},
-            last_processes: HashMap::new()
+            last_stat: Stat::boot(),
+            last_vmstat: VmStat::boot(),
+            last_processes: HashMap::new(),
+            cgroups: vec![],
+            last_pressure: HashMap::new(),
+            last_energy: HashMap::new()
         }
     }
 
-    pub fn next(&mut self) -> Result<(i32, i32)> {
-        let cpu = Cpu::from_file(File::open("/proc/stat")?)?;
-        let cpu_usage = cpu.usage(&self.last_cpu);
-        self.last_cpu = cpu;
+    pub fn next(&mut self) -> Result<model::SystemStatus> {
+        let stat = Stat::from_file(File::open("/proc/stat")?)?;
+        let uptime = uptime()?;
+        let elapsed = elapsed_since(self.last_stat.when)?;
+        let rate = |current: u64, last: u64| rate(current, last, elapsed);
+        let load = LoadAverage::from_file(File::open("/proc/loadavg")?)?;
         let memory_usage = Memory::from_file(File::open("/proc/meminfo")?)?.usage();
-        Ok((cpu_usage, memory_usage))
+
+        let status = model::SystemStatus {
+            memory: memory_usage,
+            cpu: stat.cpu.usage(&self.last_stat.cpu),
+            load_average: model::LoadAverage {
+                one: load.one,
+                five: load.five,
+                fifteen: load.fifteen
+            },
+            running_tasks: load.running,
+            total_tasks: load.total,
+            procs_running: stat.procs_running,
+            procs_blocked: stat.procs_blocked,
+            context_switches: rate(stat.context_switches, self.last_stat.context_switches),
+            interrupts: rate(stat.interrupts, self.last_stat.interrupts),
+            forks: rate(stat.forks, self.last_stat.forks),
+            uptime: uptime as u64,
+            boot_time: stat.boot_time,
+            sensors: hwmon::sensors()
+        };
+        self.last_stat = stat;
+        Ok(status)
     }
 
-    pub fn next_processes(&mut self) -> Vec<(i32,f64)> {
+    pub fn next_vmstat(&mut self) -> Result<model::VmActivity> {
+        let vmstat = VmStat::from_file(File::open("/proc/vmstat")?)?;
+        let elapsed = elapsed_since(self.last_vmstat.when)?;
+        let last = &self.last_vmstat;
+        let rate = |current: u64, last: u64| rate(current, last, elapsed);
+
+        let activity = model::VmActivity {
+            // pgfault counts both minor and major faults
+            minor_faults: rate(vmstat.faults - vmstat.major_faults, last.faults - last.major_faults),
+            major_faults: rate(vmstat.major_faults, last.major_faults),
+            swap_in: rate(vmstat.swap_in, last.swap_in),
+            swap_out: rate(vmstat.swap_out, last.swap_out),
+            page_in: rate(vmstat.page_in, last.page_in),
+            page_out: rate(vmstat.page_out, last.page_out),
+            oom_kills: rate(vmstat.oom_kills, last.oom_kills)
+        };
+        self.last_vmstat = vmstat;
+        Ok(activity)
+    }
+
+    pub fn next_processes(&mut self) -> Vec<model::Process> {
         //let mut processes = HashMap::with_capacity(self.last_processes.len());
         let mut result = vec![];
         let mut to_untrack = vec![];
+        // RLIMIT_NPROC limits the threads of every process of the real user, not only the process ones
+        let user_threads = if self.last_processes.is_empty() { HashMap::new() } else { Monitor::user_threads() };
         for (&pid, last_process) in &mut self.last_processes {
             match Monitor::get_process(pid) {
                 Ok(current_process) => {
-                    result.push((pid, current_process.usage(last_process)));
+                    let limits = Monitor::get_limits(pid).unwrap_or_default();
+                    let threads = Monitor::get_status(pid).ok()
+                        .and_then(|status| user_threads.get(&status.uid).copied())
+                        .unwrap_or(current_process.threads);
+                    result.push(model::Process {
+                        pid,
+                        cpu: current_process.usage(last_process),
+                        open_files: Monitor::open_files(pid).ok().map(|used| model::ResourceLimit {
+                            used,
+                            limit: limits.open_files
+                        }),
+                        threads: model::ResourceLimit {
+                            used: threads,
+                            limit: limits.processes
+                        },
+                        virtual_memory: model::ResourceLimit {
+                            used: current_process.virtual_memory,
+                            limit: limits.address_space
+                        },
+                        oom_score: oom::oom_score(pid).ok(),
+                        oom_score_adj: oom::oom_score_adj(pid).ok()
+                    });
                     
                     last_process.total_time = current_process.total_time;
                     last_process.when = current_process.when;
@@ -54,8 +137,75 @@ impl Monitor {
         result
     }
 
+    pub fn next_energy(&mut self) -> Vec<model::RaplZone> {
+        let mut zones = vec![];
+        for zone in rapl::zones() {
+            let now = Instant::now();
+            let power = self.last_energy.insert(zone.id.clone(), (zone.energy, now)).map(|(last, when)| {
+                // The counter wraps around at max_energy_range_uj
+                let consumed = if zone.energy >= last {
+                    zone.energy - last
+                } else {
+                    zone.max_energy.saturating_sub(last) + zone.energy
+                };
+                let elapsed = now.duration_since(when).as_secs_f64();
+                if elapsed > 0.0 {
+                    consumed as f64 / 1_000_000.0 / elapsed
+                } else {
+                    0.0
+                }
+            });
+            zones.push(model::RaplZone {
+                id: zone.id,
+                name: zone.name,
+                power
+            });
+        }
+        zones
+    }
+
+    pub fn numa_processes(&self) -> Vec<model::ProcessNuma> {
+        let mut result = vec![];
+        for &pid in self.last_processes.keys() {
+            // Finished processes are untracked by next_processes
+            match numa::process_placement(pid) {
+                Ok(nodes) => result.push(model::ProcessNuma { pid, nodes }),
+                Err(err) => warn!("Cannot get NUMA placement of process {}: {:?}", pid, err)
+            }
+        }
+        result
+    }
+
     fn get_process(pid: i32) -> Result<Process>{
-        Ok(Process::from_file(File::open(format!("/proc/{}/stat", pid))?)?)
+        Process::from_file(File::open(format!("/proc/{}/stat", pid))?)
+    }
+
+    fn get_status(pid: i32) -> Result<ProcessStatus> {
+        ProcessStatus::from_file(File::open(format!("/proc/{}/status", pid))?)
+    }
+
+    /// Threads of all the processes of every real user
+    fn user_threads() -> HashMap<u32, u64> {
+        let mut threads = HashMap::new();
+        for entry in std::fs::read_dir("/proc").into_iter().flatten().flatten() {
+            if entry.file_name().to_str().and_then(|name| name.parse::<i32>().ok()).is_none() {
+                continue;
+            }
+            // The process may have finished while scanning
+            if let Ok(status) = File::open(entry.path().join("status")).map_err(anyhow::Error::from).and_then(ProcessStatus::from_file) {
+                *threads.entry(status.uid).or_insert(0) += status.threads;
+            }
+        }
+        threads
+    }
+
+    fn get_limits(pid: i32) -> Result<Limits> {
+        Limits::from_file(File::open(format!("/proc/{}/limits", pid))?)
+    }
+
+    // Only the owner (or root) can list the descriptors of a process
+    fn open_files(pid: i32) -> Result<u64> {
+        Ok(std::fs::read_dir(format!("/proc/{}/fd", pid))?.count() as u64)
     }
 
     pub fn track_process(&mut self, pid: i32) -> Result<()> {
@@ -67,6 +217,63 @@ impl Monitor {
     pub fn untrack_process(&mut self, pid: i32) {
         self.last_processes.remove(&pid);
     }
+
+    pub fn next_pressure(&mut self) -> model::PressureStatus {
+        let root = Path::new("/proc/pressure");
+        let mut cgroups = vec![];
+        for (path, dir) in self.cgroups.clone() {
+            cgroups.push(model::CgroupPressure {
+                path,
+                cpu: self.sample_pressure(dir.join("cpu.pressure")),
+                memory: self.sample_pressure(dir.join("memory.pressure")),
+                io: self.sample_pressure(dir.join("io.pressure"))
+            });
+        }
+
+        model::PressureStatus {
+            cpu: self.sample_pressure(root.join("cpu")),
+            memory: self.sample_pressure(root.join("memory")),
+            io: self.sample_pressure(root.join("io")),
+            cgroups
+        }
+    }
+
+    fn sample_pressure(&mut self, path: PathBuf) -> Option<model::Pressure> {
+        let mut pressure = pressure_from_file(File::open(&path).ok()?).ok()?;
+        let full_total = pressure.full.as_ref().map(|full| full.total).unwrap_or(0);
+        if let Some((some, full)) = self.last_pressure.insert(path, (pressure.some.total, full_total)) {
+            // The counters are reset if the cgroup is recreated
+            pressure.some.delta = pressure.some.total.saturating_sub(some);
+            if let Some(current) = pressure.full.as_mut() {
+                current.delta = current.total.saturating_sub(full);
+            }
+        }
+        Some(pressure)
+    }
+
+    /// The path is the cgroup as shown in /proc/<pid>/cgroup, like /system.slice/docker.service
+    pub fn track_cgroup(&mut self, path: &str) -> Result<()> {
+        // Hybrid setups mount the v2 hierarchy under unified
+        let root = if Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+            Path::new("/sys/fs/cgroup")
+        } else {
+            Path::new("/sys/fs/cgroup/unified")
+        };
+        let dir = root.join(path.trim_start_matches('/'));
+        if !dir.join("cgroup.procs").exists() {
+            return Err(anyhow::anyhow!("Cgroup {} not found in the cgroup v2 hierarchy", path));
+        }
+        self.untrack_cgroup(path);
+        self.cgroups.push((path.to_owned(), dir));
+        Ok(())
+    }
+
+    pub fn untrack_cgroup(&mut self, path: &str) {
+        if let Some(index) = self.cgroups.iter().position(|(tracked, _)| tracked == path) {
+            let (_, dir) = self.cgroups.remove(index);
+            self.last_pressure.retain(|file, _| !file.starts_with(&dir));
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -75,8 +282,7 @@ struct Cpu {
 }
 
 impl Cpu {
-    pub fn from_file(file: impl std::io::Read) -> Result<Cpu> {
-        let line = io::BufReader::new(file).lines().next().unwrap()?;
+    pub fn from_line(line: &str) -> Result<Cpu> {
         let re = line.split(" ").collect::<Vec<&str>>();
         Ok(Cpu{values: re[2..].iter().map(|&e| e.parse::<u64>().unwrap()).collect::<Vec<u64>>()})
     }
@@ -93,10 +299,172 @@ impl Cpu {
 
 }
 
+/// Counters of /proc/stat
+#[derive(Debug)]
+struct Stat {
+    pub cpu: Cpu,
+    pub context_switches: u64,
+    pub interrupts: u64,
+    pub forks: u64,
+    pub procs_running: u32,
+    pub procs_blocked: u32,
+    pub boot_time: u64,
+    // None for the counters at boot time
+    pub when: Option<Instant>,
+}
+
+fn stat_value<T: std::str::FromStr>(raw: &str) -> Result<T> where T::Err: std::error::Error + Send + Sync + 'static {
+    match raw.split_whitespace().next() {
+        Some(value) => Ok(value.parse::<T>()?),
+        None => Err(anyhow::anyhow!("Stat line cannot be parsed").context(raw.to_owned()))
+    }
+}
+
+impl Stat {
+    pub fn boot() -> Stat {
+        Stat {
+            cpu: Cpu{values: vec![0;10]},
+            context_switches: 0,
+            interrupts: 0,
+            forks: 0,
+            procs_running: 0,
+            procs_blocked: 0,
+            boot_time: 0,
+            when: None
+        }
+    }
+
+    pub fn from_file(file: impl std::io::Read) -> Result<Stat> {
+        let mut s = Stat::boot();
+        s.when = Some(Instant::now());
+
+        for line in io::BufReader::new(file).lines() {
+            let line = line?;
+            if let Some((field, value)) = line.split_once(' ') {
+                match field {
+                    "cpu" => s.cpu = Cpu::from_line(&line)?,
+                    "ctxt" => s.context_switches = stat_value(value)?,
+                    // The first value is the total, then one per interrupt
+                    "intr" => s.interrupts = stat_value(value)?,
+                    "processes" => s.forks = stat_value(value)?,
+                    "procs_running" => s.procs_running = stat_value(value)?,
+                    "procs_blocked" => s.procs_blocked = stat_value(value)?,
+                    "btime" => s.boot_time = stat_value(value)?,
+                    _ => continue
+                };
+            }
+        }
+
+        Ok(s)
+    }
+}
+
+/// Content of /proc/loadavg
+struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+    pub running: u32,
+    pub total: u32,
+}
+
+impl LoadAverage {
+    pub fn from_file(file: impl std::io::Read) -> Result<LoadAverage> {
+        let line = io::BufReader::new(file).lines().next().ok_or_else(|| anyhow::anyhow!("Empty loadavg"))??;
+        let params = line.split_whitespace().collect::<Vec<&str>>();
+        if params.len() < 4 {
+            return Err(anyhow::anyhow!("Loadavg cannot be parsed").context(line));
+        }
+        let (running, total) = params[3].split_once('/').ok_or_else(|| anyhow::anyhow!("Loadavg tasks cannot be parsed").context(line.clone()))?;
+        Ok(LoadAverage {
+            one: params[0].parse::<f64>()?,
+            five: params[1].parse::<f64>()?,
+            fifteen: params[2].parse::<f64>()?,
+            running: running.parse::<u32>()?,
+            total: total.parse::<u32>()?
+        })
+    }
+}
+
+/// Boot time as seconds since the epoch
+pub fn boot_time() -> Result<u64> {
+    Ok(Stat::from_file(File::open("/proc/stat")?)?.boot_time)
+}
+
+/// Seconds since boot from /proc/uptime
+fn uptime() -> Result<f64> {
+    stat_value(&std::fs::read_to_string("/proc/uptime")?)
+}
+
+/// Seconds since a sample was taken. None is the boot, so the first sample is compared with it
+fn elapsed_since(when: Option<Instant>) -> Result<f64> {
+    match when {
+        Some(when) => Ok(when.elapsed().as_secs_f64()),
+        None => uptime()
+    }
+}
+
+/// Increment per second of a counter
+fn rate(current: u64, last: u64, elapsed: f64) -> f64 {
+    if elapsed > 0.0 {
+        current.saturating_sub(last) as f64 / elapsed
+    } else {
+        0.0
+    }
+}
+
+/// Counters of /proc/vmstat
+#[derive(Debug)]
+struct VmStat {
+    pub faults: u64,
+    pub major_faults: u64,
+    pub swap_in: u64,
+    pub swap_out: u64,
+    pub page_in: u64,
+    pub page_out: u64,
+    pub oom_kills: u64,
+    // None for the counters at boot time
+    pub when: Option<Instant>,
+}
+
+impl VmStat {
+    pub fn boot() -> VmStat {
+        VmStat {
+            faults: 0, major_faults: 0, swap_in: 0, swap_out: 0, page_in: 0, page_out: 0, oom_kills: 0, when: None
+        }
+    }
+
+    pub fn from_file(file: impl std::io::Read) -> Result<VmStat> {
+        let mut v = VmStat::boot();
+        v.when = Some(Instant::now());
+
+        for line in io::BufReader::new(file).lines() {
+            let line = line?;
+            if let Some((field, value)) = line.split_once(' ') {
+                match field {
+                    "pgfault" => v.faults = stat_value(value)?,
+                    "pgmajfault" => v.major_faults = stat_value(value)?,
+                    "pswpin" => v.swap_in = stat_value(value)?,
+                    "pswpout" => v.swap_out = stat_value(value)?,
+                    "pgpgin" => v.page_in = stat_value(value)?,
+                    "pgpgout" => v.page_out = stat_value(value)?,
+                    // Only available since Linux 4.13
+                    "oom_kill" => v.oom_kills = stat_value(value)?,
+                    _ => continue
+                };
+            }
+        }
+
+        Ok(v)
+    }
+}
+
 #[derive(Debug)]
 struct Process {
     pub total_time: i32,
     pub when: SystemTime,
+    pub threads: u64,
+    pub virtual_memory: u64,
 }
 
 
@@ -104,10 +472,19 @@ struct Process {
 impl Process {
     pub fn from_file(file: impl std::io::Read) -> Result<Process> {
         let line = io::BufReader::new(file).lines().next().unwrap()?;
-        let params = line.split(" ").collect::<Vec<&str>>();
+        // The name is between parentheses and it can contain spaces, like (Web Content). The fields
+        // after it start with the state
+        let end = line.rfind(')').ok_or_else(|| anyhow::anyhow!("Process stat cannot be parsed").context(line.clone()))?;
+        let params = line[end + 1..].split_whitespace().collect::<Vec<&str>>();
+        if params.len() < 21 {
+            return Err(anyhow::anyhow!("Process stat is too short").context(line.clone()));
+        }
         Ok(Process{
-            total_time: params[13..18].iter().map(|e| e.parse::<i32>().unwrap()).sum(),
-            when: SystemTime::now()
+            // utime, stime, cutime and cstime
+            total_time: params[11..15].iter().map(|e| e.parse::<i32>()).sum::<Result<i32, _>>()?,
+            when: SystemTime::now(),
+            threads: params[17].parse::<u64>()?,
+            virtual_memory: params[20].parse::<u64>()?
         })
     }
 
@@ -117,21 +494,82 @@ impl Process {
         // I'm assuming that CLK_TCK is 100, this is why I multiply seconds by 100
         let elapsed_time = (SystemTime::now().duration_since(last.when).unwrap().as_secs()*100) as f64;
         // Return it as percentaje
-        let usage = 100.0 * (computing_time / elapsed_time);
-        usage
+        100.0 * (computing_time / elapsed_time)
+    }
+
+}
+
+
+/// Owner and threads of a process as found in /proc/<pid>/status
+#[derive(Debug, Default)]
+struct ProcessStatus {
+    pub uid: u32,
+    pub threads: u64,
+}
+
+impl ProcessStatus {
+    pub fn from_file(file: impl std::io::Read) -> Result<ProcessStatus> {
+        let mut s = ProcessStatus::default();
+
+        for line in io::BufReader::new(file).lines() {
+            let line = line?;
+            // Real, effective, saved and filesystem UIDs. The limit applies to the real one
+            if let Some(value) = line.strip_prefix("Uid:") {
+                s.uid = stat_value(value)?;
+            } else if let Some(value) = line.strip_prefix("Threads:") {
+                s.threads = stat_value(value)?;
+            }
+        }
+
+        Ok(s)
     }
+}
 
+/// Soft limits of a process as found in /proc/<pid>/limits. None means unlimited
+#[derive(Debug, Default)]
+struct Limits {
+    pub open_files: Option<u64>,
+    pub processes: Option<u64>,
+    pub address_space: Option<u64>,
 }
 
+fn limit_value(raw: &str) -> Result<Option<u64>> {
+    match raw.split_whitespace().next() {
+        Some("unlimited") => Ok(None),
+        Some(value) => Ok(Some(value.parse::<u64>()?)),
+        None => Err(anyhow::anyhow!("Limit line cannot be parsed").context(raw.to_owned()))
+    }
+}
+
+impl Limits {
+    pub fn from_file(file: impl std::io::Read) -> Result<Limits> {
+        let mut l = Limits::default();
 
+        for line in io::BufReader::new(file).lines() {
+            let line = line?;
+            if let Some(value) = line.strip_prefix("Max open files") {
+                l.open_files = limit_value(value)?;
+            } else if let Some(value) = line.strip_prefix("Max processes") {
+                l.processes = limit_value(value)?;
+            } else if let Some(value) = line.strip_prefix("Max address space") {
+                l.address_space = limit_value(value)?;
+            }
+        }
+
+        Ok(l)
+    }
+}
 
 
 pub struct Memory {
-    total: i32,
+    pub total: i32,
     free: i32,
+    pub available: i32,
     buffers: i32,
     cached: i32,
-    reclaimable: i32
+    reclaimable: i32,
+    pub swap_total: i32,
+    pub swap_free: i32
 }
 
 fn memory_value(raw: &str) -> Result<i32> {
@@ -145,7 +583,7 @@ fn memory_value(raw: &str) -> Result<i32> {
 impl Memory {
     pub fn from_file(file: impl std::io::Read) -> Result<Memory> {
         let mut m = Memory {
-            total:0, free: 0, buffers: 0, cached: 0, reclaimable: 0
+            total:0, free: 0, available: 0, buffers: 0, cached: 0, reclaimable: 0, swap_total: 0, swap_free: 0
         };
 
         for line in io::BufReader::new(file).lines() {
@@ -153,9 +591,12 @@ impl Memory {
                 match field {
                     "MemTotal" => m.total = memory_value(value)?,
                     "MemFree" => m.free = memory_value(value)?,
+                    "MemAvailable" => m.available = memory_value(value)?,
                     "Buffers" => m.buffers = memory_value(value)?,
                     "Cached" => m.cached = memory_value(value)?,
                     "SReclaimable" => m.reclaimable = memory_value(value)?,
+                    "SwapTotal" => m.swap_total = memory_value(value)?,
+                    "SwapFree" => m.swap_free = memory_value(value)?,
                     _ => continue
                 };
             }
@@ -170,3 +611,61 @@ impl Memory {
     }
 }
 
+
+
+fn pressure_value(raw: &str) -> Result<model::PressureStats> {
+    let mut stats = model::PressureStats { avg10: 0.0, avg60: 0.0, avg300: 0.0, total: 0, delta: 0 };
+    for pair in raw.split_whitespace() {
+        match pair.split_once('=') {
+            Some(("avg10", value)) => stats.avg10 = value.parse::<f64>()?,
+            Some(("avg60", value)) => stats.avg60 = value.parse::<f64>()?,
+            Some(("avg300", value)) => stats.avg300 = value.parse::<f64>()?,
+            Some(("total", value)) => stats.total = value.parse::<u64>()?,
+            _ => continue
+        };
+    }
+    stats.delta = stats.total;
+    Ok(stats)
+}
+
+/// Parses a pressure stall information file like /proc/pressure/memory
+pub fn pressure_from_file(file: impl std::io::Read) -> Result<model::Pressure> {
+    let mut some = None;
+    let mut full = None;
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        if let Some(value) = line.strip_prefix("some ") {
+            some = Some(pressure_value(value)?);
+        } else if let Some(value) = line.strip_prefix("full ") {
+            full = Some(pressure_value(value)?);
+        }
+    }
+
+    match some {
+        Some(some) => Ok(model::Pressure { some, full }),
+        None => Err(anyhow::anyhow!("Pressure file has no \"some\" line"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_name_with_spaces() {
+        let stat = "4242 (Web Content) S 1 4242 4242 0 -1 4194560 3051 0 0 0 120 30 5 2 -2 0 27 0 123456 2147483648 512 18446744073709551615 1 1 0 0 0 0 0 0 0 0 0 0 17 3 0 0 0 0 0\n";
+        let process = Process::from_file(stat.as_bytes()).unwrap();
+        assert_eq!(process.total_time, 157);
+        assert_eq!(process.threads, 27);
+        assert_eq!(process.virtual_memory, 2147483648);
+    }
+
+    #[test]
+    fn process_name_with_parentheses() {
+        let stat = "7 (a) b) R 1 7 7 0 -1 0 0 0 0 0 1 2 3 4 20 0 1 0 100 4096 1 0\n";
+        let process = Process::from_file(stat.as_bytes()).unwrap();
+        assert_eq!(process.total_time, 10);
+        assert_eq!(process.threads, 1);
+        assert_eq!(process.virtual_memory, 4096);
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::model::{NumaNode, NumaMemory, NumaPages};
+use crate::sysfs::{read_cpu_list, numbered_entries};
+
+const NODE: &str = "/sys/devices/system/node";
+
+/// Values of a node meminfo file like "Node 0 MemTotal:  4947704 kB", in bytes
+fn node_meminfo(dir: &Path) -> BTreeMap<String, u64> {
+    let mut values = BTreeMap::new();
+    let meminfo = std::fs::read_to_string(dir.join("meminfo")).unwrap_or_default();
+    for line in meminfo.lines() {
+        if let Some((field, value)) = line.split_once(':') {
+            let field = field.split_whitespace().last().unwrap_or_default();
+            if let Some(Ok(value)) = value.split_whitespace().next().map(|value| value.parse::<u64>()) {
+                values.insert(field.to_owned(), value * 1024);
+            }
+        }
+    }
+    values
+}
+
+/// Counters of a node numastat file
+fn node_numastat(dir: &Path) -> BTreeMap<String, u64> {
+    let mut values = BTreeMap::new();
+    let numastat = std::fs::read_to_string(dir.join("numastat")).unwrap_or_default();
+    for line in numastat.lines() {
+        if let Some((field, value)) = line.split_once(' ') {
+            if let Ok(value) = value.trim().parse::<u64>() {
+                values.insert(field.to_owned(), value);
+            }
+        }
+    }
+    values
+}
+
+/// NUMA nodes with their CPUs and memory
+pub fn nodes() -> Vec<NumaNode> {
+    numbered_entries(NODE, "node")
+        .into_iter()
+        .map(|(node, dir)| NumaNode {
+            node,
+            cpus: read_cpu_list(dir.join("cpulist")),
+            memory: node_meminfo(&dir).get("MemTotal").copied().unwrap_or(0)
+        })
+        .collect()
+}
+
+/// Memory usage and allocation counters of every NUMA node
+pub fn memory_status() -> Vec<NumaMemory> {
+    let mut nodes = vec![];
+    for (node, dir) in numbered_entries(NODE, "node") {
+        let meminfo = node_meminfo(&dir);
+        let numastat = node_numastat(&dir);
+        let total = meminfo.get("MemTotal").copied().unwrap_or(0);
+        let free = meminfo.get("MemFree").copied().unwrap_or(0);
+        nodes.push(NumaMemory {
+            node,
+            total,
+            free,
+            used: meminfo.get("MemUsed").copied().unwrap_or(total.saturating_sub(free)),
+            numa_hit: numastat.get("numa_hit").copied().unwrap_or(0),
+            numa_miss: numastat.get("numa_miss").copied().unwrap_or(0),
+            numa_foreign: numastat.get("numa_foreign").copied().unwrap_or(0),
+            local_node: numastat.get("local_node").copied().unwrap_or(0),
+            other_node: numastat.get("other_node").copied().unwrap_or(0)
+        });
+    }
+    nodes
+}
+
+/// Memory of a process placed on every node, from /proc/<pid>/numa_maps
+pub fn process_placement(pid: i32) -> Result<Vec<NumaPages>> {
+    let mut memory = BTreeMap::new();
+    let maps = std::fs::read_to_string(format!("/proc/{}/numa_maps", pid))?;
+    for line in maps.lines() {
+        let fields = line.split_whitespace();
+        let page_size = fields.clone()
+            .find_map(|field| field.strip_prefix("kernelpagesize_kB="))
+            .and_then(|size| size.parse::<u64>().ok())
+            .unwrap_or(4) * 1024;
+        // Pages on every node are reported like N0=12 N1=3
+        for field in fields {
+            let pages = field.strip_prefix('N')
+                .and_then(|field| field.split_once('='))
+                .and_then(|(node, pages)| Some((node.parse::<u32>().ok()?, pages.parse::<u64>().ok()?)));
+            if let Some((node, pages)) = pages {
+                *memory.entry(node).or_insert(0) += pages * page_size;
+            }
+        }
+    }
+    Ok(memory.into_iter().map(|(node, memory)| NumaPages { node, memory }).collect())
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use std::fs::{self, File};
+use crate::model::{OomRisk, OomCandidate};
+use crate::monitor::{Memory, pressure_from_file};
+
+fn read_value(path: String) -> Result<i32> {
+    Ok(fs::read_to_string(path)?.trim().parse::<i32>()?)
+}
+
+pub fn oom_score(pid: i32) -> Result<i32> {
+    read_value(format!("/proc/{}/oom_score", pid))
+}
+
+pub fn oom_score_adj(pid: i32) -> Result<i32> {
+    read_value(format!("/proc/{}/oom_score_adj", pid))
+}
+
+// Resident memory in kB from /proc/<pid>/status. Kernel threads have no VmRSS
+fn resident_memory(pid: i32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    line["VmRSS:".len()..].split_whitespace().next()?.parse::<u64>().ok()
+}
+
+/// Memory status and the processes the OOM killer would choose first. Only the `candidates`
+/// processes with the highest score are returned
+pub fn oom_risk(candidates: usize) -> Result<OomRisk> {
+    let memory = Memory::from_file(File::open("/proc/meminfo")?)?;
+    let pressure = File::open("/proc/pressure/memory").ok().and_then(|file| pressure_from_file(file).ok());
+
+    // Every process is scanned because the score depends on the memory used
+    let mut scores = vec![];
+    for entry in fs::read_dir("/proc")?.flatten() {
+        let pid = match entry.file_name().to_str().and_then(|name| name.parse::<i32>().ok()) {
+            Some(pid) => pid,
+            None => continue
+        };
+        // The process may have finished while scanning
+        if let Ok(score) = oom_score(pid) {
+            scores.push((pid, score));
+        }
+    }
+    scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+    let mut ranked = vec![];
+    for (pid, score) in scores {
+        if ranked.len() >= candidates {
+            break;
+        }
+        // Kernel threads cannot be killed
+        let memory = match resident_memory(pid) {
+            Some(memory) => memory,
+            None => continue
+        };
+        // -1000 disables the OOM killer for the process
+        let adjust = oom_score_adj(pid).unwrap_or(0);
+        if adjust == -1000 {
+            continue;
+        }
+        ranked.push(OomCandidate {
+            pid,
+            name: fs::read_to_string(format!("/proc/{}/comm", pid)).unwrap_or_default().trim().to_string(),
+            oom_score: score,
+            oom_score_adj: adjust,
+            memory: memory * 1024
+        });
+    }
+
+    Ok(OomRisk {
+        memory_total: memory.total as u64 * 1024,
+        memory_available: memory.available as u64 * 1024,
+        swap_total: memory.swap_total as u64 * 1024,
+        swap_used: (memory.swap_total - memory.swap_free) as u64 * 1024,
+        pressure,
+        candidates: ranked
+    })
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use crate::model::{OsRelease, KernelInfo};
+use crate::sysfs::read_string;
+
+/// Removes the quotes of an os-release value and its escapes
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let quoted = value.len() >= 2 && (value.starts_with('"') && value.ends_with('"') || value.starts_with('\'') && value.ends_with('\''));
+    if !quoted {
+        return value.to_owned();
+    }
+    let mut unquoted = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                unquoted.push(escaped);
+            }
+        } else {
+            unquoted.push(c);
+        }
+    }
+    unquoted
+}
+
+/// Parses /etc/os-release (or /usr/lib/os-release). None if none of them exists
+pub fn os_release() -> Option<OsRelease> {
+    let content = std::fs::read_to_string("/etc/os-release")
+        .or_else(|_| std::fs::read_to_string("/usr/lib/os-release"))
+        .ok()?;
+    Some(parse_os_release(&content))
+}
+
+fn parse_os_release(content: &str) -> OsRelease {
+    let mut fields = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            fields.insert(key.trim().to_owned(), unquote(value));
+        }
+    }
+
+    let field = |key: &str| fields.get(key).cloned();
+    OsRelease {
+        // ID defaults to linux when not set
+        id: field("ID").unwrap_or_else(|| "linux".to_owned()),
+        id_like: field("ID_LIKE").map(|ids| ids.split_whitespace().map(|id| id.to_owned()).collect()).unwrap_or_default(),
+        name: field("NAME"),
+        pretty_name: field("PRETTY_NAME"),
+        version: field("VERSION"),
+        version_id: field("VERSION_ID"),
+        version_codename: field("VERSION_CODENAME"),
+        variant: field("VARIANT"),
+        variant_id: field("VARIANT_ID"),
+        build_id: field("BUILD_ID"),
+        fields
+    }
+}
+
+/// Version numbers and flavor of a kernel release like 5.15.0-91-generic
+fn parse_release(release: &str) -> (u32, u32, u32, Option<String>) {
+    let end = release.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(release.len());
+    let mut numbers = release[..end].split('.').map(|number| number.parse::<u32>().unwrap_or(0));
+    let flavor = release[end..].trim_start_matches(['-', '+', '_']);
+    (
+        numbers.next().unwrap_or(0),
+        numbers.next().unwrap_or(0),
+        numbers.next().unwrap_or(0),
+        if flavor.is_empty() { None } else { Some(flavor.to_owned()) }
+    )
+}
+
+/// Running kernel with its version parsed from the release
+pub fn kernel() -> KernelInfo {
+    let release = read_string("/proc/sys/kernel/osrelease").unwrap_or_default();
+    let (major, minor, patch, flavor) = parse_release(&release);
+
+    KernelInfo {
+        major,
+        minor,
+        patch,
+        flavor,
+        // Available since Linux 6.1, the compilation target is a good guess for older ones
+        architecture: read_string("/proc/sys/kernel/arch").unwrap_or_else(|| std::env::consts::ARCH.to_owned()),
+        command_line: read_string("/proc/cmdline").unwrap_or_default(),
+        release
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ubuntu_release() {
+        assert_eq!(parse_release("5.15.0-91-generic"), (5, 15, 0, Some("91-generic".to_owned())));
+    }
+
+    #[test]
+    fn raspberry_release() {
+        assert_eq!(parse_release("6.1.21-v8+"), (6, 1, 21, Some("v8+".to_owned())));
+    }
+
+    #[test]
+    fn short_release() {
+        assert_eq!(parse_release("6.8"), (6, 8, 0, None));
+        assert_eq!(parse_release("4.9.337-tegra"), (4, 9, 337, Some("tegra".to_owned())));
+        assert_eq!(parse_release(""), (0, 0, 0, None));
+    }
+
+    #[test]
+    fn os_release_fields() {
+        let content = "# comment\nNAME=\"Ubuntu\"\nVERSION_ID=\"22.04\"\nID=ubuntu\nID_LIKE=debian\nPRETTY_NAME='Ubuntu \\\"Jammy\\\"'\n";
+        let release = parse_os_release(content);
+        assert_eq!(release.id, "ubuntu");
+        assert_eq!(release.id_like, vec!["debian"]);
+        assert_eq!(release.name.as_deref(), Some("Ubuntu"));
+        assert_eq!(release.version_id.as_deref(), Some("22.04"));
+        assert_eq!(release.pretty_name.as_deref(), Some("Ubuntu \"Jammy\""));
+        assert_eq!(release.version, None);
+    }
+
+    #[test]
+    fn os_release_default_id() {
+        assert_eq!(parse_os_release("NAME=Linux\n").id, "linux");
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use crate::model::PciDevice;
+use crate::sysfs::{read_string, read_value, driver};
+
+const PCI_IDS: [&str; 3] = ["/usr/share/hwdata/pci.ids", "/usr/share/misc/pci.ids", "/usr/share/pci.ids"];
+
+/// Names from the PCI database. Only the vendors present in the machine are loaded
+#[derive(Default)]
+struct PciIds {
+    vendors: HashMap<u16, String>,
+    devices: HashMap<(u16, u16), String>,
+    subsystems: HashMap<(u16, u16, u16, u16), String>,
+    classes: HashMap<u8, String>,
+    subclasses: HashMap<(u8, u8), String>,
+}
+
+/// Splits a database line like "10de  NVIDIA Corporation" into the id and the name
+fn entry(line: &str) -> Option<(&str, &str)> {
+    let (id, name) = line.trim().split_once(' ')?;
+    Some((id, name.trim()))
+}
+
+impl PciIds {
+    fn load(vendors: &HashSet<u16>) -> PciIds {
+        match PCI_IDS.iter().find_map(|path| std::fs::read_to_string(path).ok()) {
+            Some(content) => PciIds::parse(&content, vendors),
+            None => PciIds::default()
+        }
+    }
+
+    fn parse(content: &str, vendors: &HashSet<u16>) -> PciIds {
+        let mut ids = PciIds::default();
+        // Vendors and classes start a section, devices are indented by one tab and subsystems by two
+        let mut vendor = None;
+        let mut device = None;
+        let mut class = None;
+        for line in content.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if let Some(line) = line.strip_prefix("\t\t") {
+                if let (Some(vendor), Some(device)) = (vendor, device) {
+                    let mut fields = line.split_whitespace();
+                    let subvendor = fields.next().and_then(|id| u16::from_str_radix(id, 16).ok());
+                    let subdevice = fields.next().and_then(|id| u16::from_str_radix(id, 16).ok());
+                    if let (Some(subvendor), Some(subdevice)) = (subvendor, subdevice) {
+                        ids.subsystems.insert((vendor, device, subvendor, subdevice), fields.collect::<Vec<_>>().join(" "));
+                    }
+                }
+            } else if let Some(line) = line.strip_prefix('\t') {
+                if let Some((id, name)) = entry(line) {
+                    if let Some(vendor) = vendor {
+                        device = u16::from_str_radix(id, 16).ok();
+                        if let Some(device) = device {
+                            ids.devices.insert((vendor, device), name.to_owned());
+                        }
+                    } else if let Some(class) = class {
+                        if let Ok(subclass) = u8::from_str_radix(id, 16) {
+                            ids.subclasses.insert((class, subclass), name.to_owned());
+                        }
+                    }
+                }
+            } else if let Some(line) = line.strip_prefix("C ") {
+                vendor = None;
+                class = entry(line)
+                    .and_then(|(id, name)| Some((u8::from_str_radix(id, 16).ok()?, name)))
+                    .map(|(id, name)| {
+                        ids.classes.insert(id, name.to_owned());
+                        id
+                    });
+            } else {
+                class = None;
+                device = None;
+                vendor = entry(line)
+                    .and_then(|(id, name)| Some((u16::from_str_radix(id, 16).ok()?, name)))
+                    .filter(|(id, _)| vendors.contains(id))
+                    .map(|(id, name)| {
+                        ids.vendors.insert(id, name.to_owned());
+                        id
+                    });
+            }
+        }
+        ids
+    }
+}
+
+/// Name of the base class, used when there is no PCI database
+fn base_class(class: u8) -> Option<&'static str> {
+    let name = match class {
+        0x00 => "Unclassified device",
+        0x01 => "Mass storage controller",
+        0x02 => "Network controller",
+        0x03 => "Display controller",
+        0x04 => "Multimedia controller",
+        0x05 => "Memory controller",
+        0x06 => "Bridge",
+        0x07 => "Communication controller",
+        0x08 => "Generic system peripheral",
+        0x09 => "Input device controller",
+        0x0a => "Docking station",
+        0x0b => "Processor",
+        0x0c => "Serial bus controller",
+        0x0d => "Wireless controller",
+        0x0e => "Intelligent controller",
+        0x0f => "Satellite communications controller",
+        0x10 => "Encryption controller",
+        0x11 => "Signal processing controller",
+        0x12 => "Processing accelerators",
+        0x13 => "Non-Essential Instrumentation",
+        0x40 => "Coprocessor",
+        _ => return None
+    };
+    Some(name)
+}
+
+/// Hexadecimal attributes like 0x10de
+fn read_hex(path: impl AsRef<Path>) -> Option<u32> {
+    u32::from_str_radix(read_string(path)?.trim_start_matches("0x"), 16).ok()
+}
+
+/// Every PCI device of the machine (GPUs, NICs, NVMe, accelerators...) with the names resolved from
+/// the pci.ids database if it is installed
+pub fn pci_devices() -> Vec<PciDevice> {
+    let mut entries = std::fs::read_dir("/sys/bus/pci/devices").into_iter().flatten().flatten().collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut devices = vec![];
+    for entry in entries {
+        let dir = entry.path();
+        let (class, vendor_id, device_id) = match (read_hex(dir.join("class")), read_hex(dir.join("vendor")), read_hex(dir.join("device"))) {
+            (Some(class), Some(vendor), Some(device)) => (class, vendor as u16, device as u16),
+            _ => continue
+        };
+        devices.push(PciDevice {
+            address: entry.file_name().to_string_lossy().into_owned(),
+            class,
+            class_name: None,
+            vendor_id,
+            device_id,
+            subsystem_vendor_id: read_hex(dir.join("subsystem_vendor")).map(|id| id as u16),
+            subsystem_device_id: read_hex(dir.join("subsystem_device")).map(|id| id as u16),
+            vendor_name: None,
+            device_name: None,
+            subsystem_name: None,
+            driver: driver(&dir),
+            // -1 when the platform has no NUMA
+            numa_node: read_value::<i32>(dir.join("numa_node")).and_then(|node| u32::try_from(node).ok()),
+            link_speed: read_string(dir.join("current_link_speed")),
+            link_width: read_value(dir.join("current_link_width"))
+        });
+    }
+
+    let ids = PciIds::load(&devices.iter().map(|device| device.vendor_id).collect());
+    for device in &mut devices {
+        let base = (device.class >> 16) as u8;
+        let subclass = (device.class >> 8) as u8;
+        device.class_name = ids.subclasses.get(&(base, subclass))
+            .or_else(|| ids.classes.get(&base))
+            .cloned()
+            .or_else(|| base_class(base).map(|name| name.to_owned()));
+        device.vendor_name = ids.vendors.get(&device.vendor_id).cloned();
+        device.device_name = ids.devices.get(&(device.vendor_id, device.device_id)).cloned();
+        if let (Some(subvendor), Some(subdevice)) = (device.subsystem_vendor_id, device.subsystem_device_id) {
+            device.subsystem_name = ids.subsystems.get(&(device.vendor_id, device.device_id, subvendor, subdevice)).cloned();
+        }
+    }
+    devices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNIPPET: &str = "\
+# List of PCI ID's
+8086  Intel Corporation
+\t1533  I210 Gigabit Network Connection
+\t\t8086 0001  Ethernet Server Adapter I210-T1
+10de  NVIDIA Corporation
+\t2204  GA102 [GeForce RTX 3090]
+\t\t1043 87b3  ROG Strix GeForce RTX 3090
+\t1aef  GA102 High Definition Audio Controller
+1af4  Red Hat, Inc.
+\t1000  Virtio network device
+
+C 02  Network controller
+\t00  Ethernet controller
+C 03  Display controller
+\t00  VGA compatible controller
+\t\t00  VGA controller
+\t02  3D controller
+";
+
+    #[test]
+    fn names_of_present_vendors() {
+        let ids = PciIds::parse(SNIPPET, &HashSet::from([0x10de, 0x8086]));
+        assert_eq!(ids.vendors.get(&0x10de).map(|name| name.as_str()), Some("NVIDIA Corporation"));
+        assert_eq!(ids.vendors.get(&0x8086).map(|name| name.as_str()), Some("Intel Corporation"));
+        assert_eq!(ids.devices.get(&(0x10de, 0x2204)).map(|name| name.as_str()), Some("GA102 [GeForce RTX 3090]"));
+        assert_eq!(ids.devices.get(&(0x10de, 0x1aef)).map(|name| name.as_str()), Some("GA102 High Definition Audio Controller"));
+        assert_eq!(ids.subsystems.get(&(0x10de, 0x2204, 0x1043, 0x87b3)).map(|name| name.as_str()), Some("ROG Strix GeForce RTX 3090"));
+        assert_eq!(ids.subsystems.get(&(0x8086, 0x1533, 0x8086, 0x0001)).map(|name| name.as_str()), Some("Ethernet Server Adapter I210-T1"));
+    }
+
+    #[test]
+    fn other_vendors_are_skipped() {
+        let ids = PciIds::parse(SNIPPET, &HashSet::from([0x10de]));
+        assert!(!ids.vendors.contains_key(&0x1af4));
+        assert!(!ids.devices.contains_key(&(0x1af4, 0x1000)));
+        assert!(!ids.devices.contains_key(&(0x8086, 0x1533)));
+        assert_eq!(ids.vendors.len(), 1);
+    }
+
+    #[test]
+    fn classes() {
+        let ids = PciIds::parse(SNIPPET, &HashSet::new());
+        assert_eq!(ids.classes.get(&0x02).map(|name| name.as_str()), Some("Network controller"));
+        assert_eq!(ids.subclasses.get(&(0x03, 0x00)).map(|name| name.as_str()), Some("VGA compatible controller"));
+        assert_eq!(ids.subclasses.get(&(0x03, 0x02)).map(|name| name.as_str()), Some("3D controller"));
+        // Programming interfaces are not subclasses
+        assert_eq!(ids.subclasses.len(), 3);
+    }
+
+    #[test]
+    fn builtin_classes() {
+        assert_eq!(base_class(0x03), Some("Display controller"));
+        assert_eq!(base_class(0x12), Some("Processing accelerators"));
+        assert_eq!(base_class(0xff), None);
+    }
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+use crate::model::PowerSupply;
+use crate::sysfs::{read_string, read_value};
+
+const POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+// Values are exposed in micro units (µV, µA, µW, µWh, µAh)
+fn read_micro(dir: &Path, attribute: &str) -> Option<f64> {
+    read_value::<i64>(dir.join(attribute)).map(|value| value as f64 / 1_000_000.0)
+}
+
+fn power_supply(dir: &Path) -> Option<PowerSupply> {
+    let name = dir.file_name()?.to_str()?.to_owned();
+    let voltage = read_micro(dir, "voltage_now");
+    // Some drivers report the current as negative while discharging
+    let current = read_micro(dir, "current_now").map(f64::abs);
+    let power = read_micro(dir, "power_now").map(f64::abs)
+        .or_else(|| Some(current? * voltage?));
+    // Batteries report either energy (µWh) or charge (µAh)
+    let energy = read_micro(dir, "energy_now")
+        .or_else(|| Some(read_micro(dir, "charge_now")? * voltage?));
+    let energy_full = read_micro(dir, "energy_full")
+        .or_else(|| Some(read_micro(dir, "charge_full")? * voltage?));
+    let status = read_string(dir.join("status"));
+
+    let time_to_empty = read_value::<u64>(dir.join("time_to_empty_now")).or_else(|| {
+        match (status.as_deref(), energy, power) {
+            (Some("Discharging"), Some(energy), Some(power)) if power > 0.0 => Some((energy / power * 3600.0) as u64),
+            _ => None
+        }
+    });
+
+    Some(PowerSupply {
+        name,
+        kind: read_string(dir.join("type")).unwrap_or_else(|| "Unknown".to_owned()),
+        online: read_value::<u8>(dir.join("online")).map(|online| online == 1),
+        status,
+        capacity: read_value(dir.join("capacity")),
+        voltage,
+        current,
+        power,
+        energy,
+        energy_full,
+        time_to_empty
+    })
+}
+
+/// AC adapters, batteries and UPS of the machine
+pub fn power_supplies() -> Vec<PowerSupply> {
+    let mut supplies = vec![];
+    if let Ok(entries) = std::fs::read_dir(POWER_SUPPLY) {
+        for entry in entries.flatten() {
+            if let Some(supply) = power_supply(&entry.path()) {
+                supplies.push(supply);
+            }
+        }
+    }
+    supplies.sort_by(|a, b| a.name.cmp(&b.name));
+    supplies
+}
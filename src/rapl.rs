@@ -0,0 +1,41 @@
+use crate::sysfs::{read_string, read_value};
+
+const POWERCAP: &str = "/sys/class/powercap";
+
+/// Energy counter of a RAPL zone
+#[derive(Debug)]
+pub struct Zone {
+    /// Zone directory like intel-rapl:0 or intel-rapl:0:1
+    pub id: String,
+    /// Domain like package-0, core, uncore, dram or psys
+    pub name: String,
+    /// Energy counter in microjoules
+    pub energy: u64,
+    /// Value where the energy counter wraps around
+    pub max_energy: u64,
+}
+
+/// RAPL zones and subzones. AMD processors use the same intel-rapl interface. Since Linux 5.10 the
+/// counters are readable only by root, zones that cannot be read are skipped
+pub fn zones() -> Vec<Zone> {
+    let mut dirs = vec![];
+    if let Ok(entries) = std::fs::read_dir(POWERCAP) {
+        for entry in entries.flatten() {
+            let id = entry.file_name().to_str().unwrap_or_default().to_owned();
+            // intel-rapl itself is the control type, not a zone
+            if id.starts_with("intel-rapl:") {
+                dirs.push((id, entry.path()));
+            }
+        }
+    }
+    dirs.sort();
+
+    dirs.into_iter().filter_map(|(id, dir)| {
+        Some(Zone {
+            name: read_string(dir.join("name"))?,
+            energy: read_value(dir.join("energy_uj"))?,
+            max_energy: read_value(dir.join("max_energy_range_uj"))?,
+            id
+        })
+    }).collect()
+}
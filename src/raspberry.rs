@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+use crate::model::{RaspberryStatus, ThrottleFlags};
+use crate::sysfs::read_string;
+use crate::{board, thermal};
+
+/// The firmware get_throttled attribute. Its parent directory depends on the SoC (soc:firmware on
+/// Pi 4 and older, soc@107c000000:firmware on Pi 5)
+fn throttled_path() -> Option<PathBuf> {
+    let default = Path::new("/sys/devices/platform/soc/soc:firmware/get_throttled");
+    if default.exists() {
+        return Some(default.to_path_buf());
+    }
+    for bus in std::fs::read_dir("/sys/devices/platform").ok()?.flatten() {
+        for device in std::fs::read_dir(bus.path()).into_iter().flatten().flatten() {
+            let path = device.path().join("get_throttled");
+            if device.file_name().to_string_lossy().ends_with(":firmware") && path.exists() {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+/// Decodes the 4 flags starting at `offset`. The low bits are the current state and bit 16 onwards
+/// the sticky ones, set if it happened since boot
+fn flags(value: u32, offset: u32) -> ThrottleFlags {
+    let bit = |n: u32| value & (1 << (offset + n)) != 0;
+    ThrottleFlags {
+        under_voltage: bit(0),
+        arm_frequency_capped: bit(1),
+        throttled: bit(2),
+        soft_temperature_limit: bit(3)
+    }
+}
+
+/// Throttling status of a Raspberry Pi. None on other machines
+pub fn status() -> Option<RaspberryStatus> {
+    let model = board::model()?;
+    if !model.starts_with("Raspberry Pi") {
+        return None;
+    }
+
+    let throttled = throttled_path()
+        .and_then(read_string)
+        .and_then(|value| u32::from_str_radix(value.trim_start_matches("0x"), 16).ok());
+
+    let zones = thermal::thermal_zones();
+    let temperature = zones.iter()
+        .find(|zone| zone.kind == "cpu-thermal")
+        .or_else(|| zones.first())
+        .and_then(|zone| zone.temperature);
+
+    Some(RaspberryStatus {
+        model,
+        throttled,
+        current: throttled.map(|value| flags(value, 0)),
+        occurred: throttled.map(|value| flags(value, 16)),
+        temperature
+    })
+}
@@ -0,0 +1,75 @@
+//! Helpers to read sysfs attributes. Missing or unreadable attributes are common (old kernels,
+//! drivers not exposing them, root only files) so they are reported as None instead of errors
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Attribute content without the trailing new line. None if it cannot be read or it is empty
+pub fn read_string(path: impl AsRef<Path>) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+/// Attribute parsed as a value
+pub fn read_value<T: FromStr>(path: impl AsRef<Path>) -> Option<T> {
+    read_string(path)?.parse::<T>().ok()
+}
+
+/// Entries of a directory named as the prefix followed by a number, like thermal_zone0, sorted by the number
+pub fn numbered_entries(dir: impl AsRef<Path>, prefix: &str) -> Vec<(u32, PathBuf)> {
+    let mut entries = vec![];
+    if let Ok(dir) = fs::read_dir(dir) {
+        for entry in dir.flatten() {
+            let name = entry.file_name();
+            let index = name.to_str()
+                .and_then(|name| name.strip_prefix(prefix))
+                .and_then(|index| index.parse::<u32>().ok());
+            if let Some(index) = index {
+                entries.push((index, entry.path()));
+            }
+        }
+    }
+    entries.sort_by_key(|(index, _)| *index);
+    entries
+}
+
+/// Parses a CPU list like 0-3,8,10-11
+pub fn parse_cpu_list(list: &str) -> Vec<u32> {
+    let mut cpus = vec![];
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        let bounds = match range.split_once('-') {
+            Some((start, end)) => start.parse::<u32>().ok().zip(end.parse::<u32>().ok()),
+            None => range.parse::<u32>().ok().map(|cpu| (cpu, cpu))
+        };
+        if let Some((start, end)) = bounds {
+            cpus.extend(start..=end);
+        }
+    }
+    cpus
+}
+
+/// Attribute with a CPU list. An empty attribute (like offline when all the CPUs are online) is an empty list
+pub fn read_cpu_list(path: impl AsRef<Path>) -> Vec<u32> {
+    read_string(path).map(|list| parse_cpu_list(&list)).unwrap_or_default()
+}
+
+/// Name of the driver bound to a device, from its driver link
+pub fn driver(device: impl AsRef<Path>) -> Option<String> {
+    link_name(device.as_ref().join("driver"))
+}
+
+/// Kernel module of the driver bound to a device. None for drivers built into the kernel
+pub fn driver_module(device: impl AsRef<Path>) -> Option<String> {
+    link_name(device.as_ref().join("driver").join("module"))
+}
+
+/// Last component of the target of a symbolic link
+pub fn link_name(path: impl AsRef<Path>) -> Option<String> {
+    let target = fs::read_link(path).ok()?;
+    Some(target.file_name()?.to_str()?.to_owned())
+}
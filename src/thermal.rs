@@ -0,0 +1,56 @@
+use std::path::Path;
+use crate::model::{ThermalZone, TripPoint, CoolingDevice};
+use crate::sysfs::{read_string, read_value, numbered_entries};
+
+const THERMAL: &str = "/sys/class/thermal";
+
+// Temperatures are exposed in millidegrees Celsius
+fn read_celsius(path: impl AsRef<Path>) -> Option<f64> {
+    read_value::<i64>(path).map(|value| value as f64 / 1000.0)
+}
+
+fn cooling_device(zone: &Path, index: u32) -> Option<CoolingDevice> {
+    // cdevN links to the cooling_deviceM bound to the zone
+    let device = std::fs::canonicalize(zone.join(format!("cdev{}", index))).ok()?;
+    Some(CoolingDevice {
+        name: device.file_name()?.to_str()?.to_owned(),
+        kind: read_string(device.join("type")).unwrap_or_else(|| "Unknown".to_owned()),
+        current_state: read_value(device.join("cur_state"))?,
+        max_state: read_value(device.join("max_state"))?,
+        trip_point: read_value(zone.join(format!("cdev{}_trip_point", index)))
+    })
+}
+
+/// Thermal zones of the machine with their trip points and bound cooling devices
+pub fn thermal_zones() -> Vec<ThermalZone> {
+    let mut zones = vec![];
+    for (_, zone) in numbered_entries(THERMAL, "thermal_zone") {
+        let mut trip_points = vec![];
+        for index in 0.. {
+            let temperature = match read_celsius(zone.join(format!("trip_point_{}_temp", index))) {
+                Some(temperature) => temperature,
+                None => break
+            };
+            trip_points.push(TripPoint {
+                kind: read_string(zone.join(format!("trip_point_{}_type", index))).unwrap_or_else(|| "Unknown".to_owned()),
+                temperature,
+                hysteresis: read_celsius(zone.join(format!("trip_point_{}_hyst", index)))
+            });
+        }
+
+        let cooling_devices = numbered_entries(&zone, "cdev")
+            .into_iter()
+            .filter_map(|(index, _)| cooling_device(&zone, index))
+            .collect();
+
+        zones.push(ThermalZone {
+            name: zone.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_owned(),
+            kind: read_string(zone.join("type")).unwrap_or_else(|| "Unknown".to_owned()),
+            // Reading fails for disabled zones
+            temperature: read_celsius(zone.join("temp")),
+            trip_points,
+            cooling_devices
+        });
+    }
+    zones
+}
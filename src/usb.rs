@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::model::UsbDevice;
+use crate::sysfs::{read_string, read_value, driver};
+
+const USB: &str = "/sys/bus/usb/devices";
+
+/// Hexadecimal ids like 046d
+fn read_id(path: impl AsRef<Path>) -> Option<u16> {
+    u16::from_str_radix(&read_string(path)?, 16).ok()
+}
+
+/// Parent of a device: 1-1.2 is connected to 1-1 and 1-1 to the root hub usb1
+fn parent(name: &str) -> Option<String> {
+    if name.starts_with("usb") {
+        return None;
+    }
+    match name.rsplit_once('.') {
+        Some((parent, _)) => Some(parent.to_owned()),
+        None => Some(format!("usb{}", name.split_once('-')?.0))
+    }
+}
+
+/// Device of an interface: 1-1.2:1.0 belongs to 1-1.2. Root hub interfaces are named like 1-0:1.0
+fn interface_device(name: &str) -> Option<String> {
+    let (device, _) = name.split_once(':')?;
+    match device.strip_suffix("-0") {
+        Some(bus) => Some(format!("usb{}", bus)),
+        None => Some(device.to_owned())
+    }
+}
+
+/// Bus and port numbers of a name, so 1-2 is sorted before 1-10. Interfaces go after their device
+fn sort_key(name: &str) -> (u32, Vec<u32>, String) {
+    let (device, interface) = name.split_once(':').unwrap_or((name, ""));
+    let numbers = match device.strip_prefix("usb") {
+        Some(bus) => bus.parse::<u32>().ok().map(|bus| (bus, vec![])),
+        None => device.split_once('-').and_then(|(bus, ports)| {
+            let ports = ports.split('.').map(|port| port.parse::<u32>().ok()).collect::<Option<Vec<_>>>()?;
+            Some((bus.parse::<u32>().ok()?, ports))
+        })
+    };
+    let (bus, ports) = numbers.unwrap_or((u32::MAX, vec![]));
+    (bus, ports, interface.to_owned())
+}
+
+fn device(name: &str, dir: &Path, interface_drivers: Vec<String>) -> Option<UsbDevice> {
+    Some(UsbDevice {
+        name: name.to_owned(),
+        bus: read_value(dir.join("busnum"))?,
+        device_number: read_value(dir.join("devnum"))?,
+        port_path: read_string(dir.join("devpath")).unwrap_or_default(),
+        vendor_id: read_id(dir.join("idVendor"))?,
+        product_id: read_id(dir.join("idProduct"))?,
+        manufacturer: read_string(dir.join("manufacturer")),
+        product: read_string(dir.join("product")),
+        serial: read_string(dir.join("serial")),
+        speed: read_value(dir.join("speed")),
+        // Like 500mA
+        max_power: read_string(dir.join("bMaxPower")).and_then(|power| power.trim_end_matches("mA").parse::<u32>().ok()),
+        driver: driver(dir),
+        interface_drivers,
+        children: vec![]
+    })
+}
+
+fn attach(device: &mut UsbDevice, children: &mut BTreeMap<String, Vec<UsbDevice>>) {
+    if let Some(mut attached) = children.remove(&device.name) {
+        for child in &mut attached {
+            attach(child, children);
+        }
+        device.children = attached;
+    }
+}
+
+/// USB devices as a tree. The roots are the host controller hubs
+pub fn usb_devices() -> Vec<UsbDevice> {
+    let mut entries = std::fs::read_dir(USB).into_iter().flatten().flatten()
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+        .collect::<Vec<_>>();
+    entries.sort_by_cached_key(|(name, _)| sort_key(name));
+
+    // Interfaces are named like 1-1:1.0 and carry the useful drivers (uvcvideo, usbhid...)
+    let mut interfaces: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, dir) in &entries {
+        if let (Some(device), Some(driver)) = (interface_device(name), driver(dir)) {
+            let drivers = interfaces.entry(device).or_default();
+            if !drivers.contains(&driver) {
+                drivers.push(driver);
+            }
+        }
+    }
+
+    let mut roots = vec![];
+    let mut children: BTreeMap<String, Vec<UsbDevice>> = BTreeMap::new();
+    for (name, dir) in &entries {
+        if name.contains(':') {
+            continue;
+        }
+        let device = match device(name, dir, interfaces.remove(name).unwrap_or_default()) {
+            Some(device) => device,
+            None => continue
+        };
+        match parent(name) {
+            Some(parent) => children.entry(parent).or_default().push(device),
+            None => roots.push(device)
+        }
+    }
+
+    for root in &mut roots {
+        attach(root, &mut children);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parents() {
+        assert_eq!(parent("usb1"), None);
+        assert_eq!(parent("1-1"), Some("usb1".to_owned()));
+        assert_eq!(parent("2-1.4.3"), Some("2-1.4".to_owned()));
+    }
+
+    #[test]
+    fn interface_devices() {
+        assert_eq!(interface_device("1-1.2:1.0"), Some("1-1.2".to_owned()));
+        assert_eq!(interface_device("1-0:1.0"), Some("usb1".to_owned()));
+        assert_eq!(interface_device("10-0:1.0"), Some("usb10".to_owned()));
+        assert_eq!(interface_device("1-10:1.0"), Some("1-10".to_owned()));
+        assert_eq!(interface_device("usb1"), None);
+    }
+
+    #[test]
+    fn numeric_order() {
+        let mut names = vec!["1-10", "usb2", "1-2:1.1", "1-2", "usb1", "1-2:1.0", "1-1.10", "1-1.2", "2-1", "1-1"];
+        names.sort_by_cached_key(|name| sort_key(name));
+        assert_eq!(names, vec!["usb1", "1-1", "1-1.2", "1-1.10", "1-2", "1-2:1.0", "1-2:1.1", "1-10", "usb2", "2-1"]);
+    }
+}
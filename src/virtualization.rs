@@ -0,0 +1,90 @@
+use std::path::Path;
+use crate::model::Virtualization;
+use crate::sysfs::read_string;
+use crate::{cpu, dmi};
+
+/// Hypervisor from the DMI vendor and product strings
+fn dmi_hypervisor() -> Option<&'static str> {
+    let dmi = dmi::dmi()?;
+    let vendor = dmi.sys_vendor.unwrap_or_default();
+    let product = dmi.product_name.unwrap_or_default();
+    let hypervisor = if product.contains("KVM") {
+        "kvm"
+    } else if vendor.contains("QEMU") {
+        "qemu"
+    } else if vendor.contains("VMware") {
+        "vmware"
+    } else if vendor.contains("Microsoft") && product.contains("Virtual Machine") {
+        "hyperv"
+    } else if vendor.contains("Xen") {
+        "xen"
+    } else if vendor.contains("innotek") || product.contains("VirtualBox") {
+        "virtualbox"
+    } else if vendor.contains("Amazon EC2") {
+        "amazon"
+    } else if product.contains("Google Compute Engine") {
+        "google"
+    } else if vendor.contains("Parallels") {
+        "parallels"
+    } else {
+        return None;
+    };
+    Some(hypervisor)
+}
+
+/// Xen dom0 is the host managing the guests, although it runs on the hypervisor too
+fn xen_dom0() -> bool {
+    read_string("/proc/xen/capabilities").is_some_and(|capabilities| capabilities.contains("control_d"))
+}
+
+fn hypervisor() -> Option<String> {
+    // The dom0 sees the hypervisor in sysfs and in the cpuid bit like the guests, so it is checked first
+    if xen_dom0() {
+        return None;
+    }
+    // Paravirtualized Xen guests have no DMI
+    if let Some(kind) = read_string("/sys/hypervisor/type") {
+        return Some(kind);
+    }
+    if let Some(hypervisor) = dmi_hypervisor() {
+        return Some(hypervisor.to_owned());
+    }
+    // The cpuid hypervisor bit is set by every hypervisor but it does not tell which one. ARM guests
+    // have a hypervisor node in the devicetree instead
+    if cpu::cpu_flags().iter().any(|flag| flag == "hypervisor") || Path::new("/sys/firmware/devicetree/base/hypervisor").exists() {
+        return Some("unknown".to_owned());
+    }
+    None
+}
+
+fn container() -> Option<String> {
+    let osrelease = read_string("/proc/sys/kernel/osrelease").unwrap_or_default().to_lowercase();
+    if osrelease.contains("microsoft") || osrelease.contains("wsl") {
+        return Some("wsl".to_owned());
+    }
+
+    let cgroup = std::fs::read_to_string("/proc/1/cgroup").unwrap_or_default();
+    // Kubernetes runs its pods with docker, containerd or cri-o so it is checked first
+    if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some() || cgroup.contains("kubepods") {
+        return Some("kubernetes".to_owned());
+    }
+    if Path::new("/run/.containerenv").exists() {
+        return Some("podman".to_owned());
+    }
+    if Path::new("/.dockerenv").exists() || cgroup.contains("docker") {
+        return Some("docker".to_owned());
+    }
+    if cgroup.contains("lxc") {
+        return Some("lxc".to_owned());
+    }
+    // Written by systemd aware container managers (lxc, systemd-nspawn...)
+    read_string("/run/systemd/container")
+}
+
+/// Hypervisor and container the machine runs on
+pub fn virtualization() -> Virtualization {
+    Virtualization {
+        hypervisor: hypervisor(),
+        container: container()
+    }
+}